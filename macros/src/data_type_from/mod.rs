@@ -52,9 +52,18 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
 
                     quote!((#ident_str.into(), #crate_ref::internal::construct::field(
                         #skip,
+                        #skip,
+                        false,
+                        false,
+                        None,
                         false,
                         false,
+                        vec![],
+                        None,
+                        Default::default(),
+                        None,
                         None,
+                        Default::default(),
                         std::borrow::Cow::Borrowed(""),
                         t.#ident.into(),
                     )))
@@ -65,7 +74,7 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
                     #[automatically_derived]
                     impl From<#ident> for #crate_ref::StructType {
                         fn from(t: #ident) -> #crate_ref::StructType {
-                            #crate_ref::internal::construct::r#struct(#struct_name.into(), vec![], #crate_ref::internal::construct::struct_named(vec![#(#fields),*], None))
+                            #crate_ref::internal::construct::r#struct(#struct_name.into(), vec![], #crate_ref::internal::construct::struct_named(vec![#(#fields),*], None), false, None)
                         }
                     }
 
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use quote::quote;
-use syn::{Lit, Result};
+use syn::{Expr, Lit, Result};
 
 use crate::utils::{Attribute, AttributeValue};
 
@@ -22,6 +22,49 @@ pub enum DeprecatedType {
     },
 }
 
+/// Serialize the expression from a `#[specta(example = ...)]` attribute.
+pub fn example_as_tokens(
+    crate_ref: &proc_macro2::TokenStream,
+    example: &Option<Expr>,
+) -> proc_macro2::TokenStream {
+    match example {
+        Some(example) => quote!(#crate_ref::internal::construct::example(#example)),
+        None => quote!(None),
+    }
+}
+
+/// Parse a `#[specta(meta(key = "value"))]` attribute into `out`.
+pub fn parse_meta(attr: &Attribute, out: &mut Vec<(String, String)>) -> Result<()> {
+    let Some(AttributeValue::Attribute { attr, .. }) = &attr.value else {
+        return Err(syn::Error::new(
+            attr.value_span(),
+            "specta: expected `meta(key = \"value\")`",
+        ));
+    };
+
+    for attr in attr {
+        let key = attr.key.to_string();
+        if out.iter().any(|(k, _)| *k == key) {
+            return Err(syn::Error::new(
+                attr.key.span(),
+                format!("specta: duplicate metadata key '{key}'"),
+            ));
+        }
+        out.push((key, attr.parse_string()?));
+    }
+
+    Ok(())
+}
+
+/// Construct the `Metadata` from the `#[specta(meta(...))]` attributes.
+pub fn metadata_as_tokens(
+    crate_ref: &proc_macro2::TokenStream,
+    meta: &[(String, String)],
+) -> proc_macro2::TokenStream {
+    let entries = meta.iter().map(|(k, v)| quote!((#k.into(), #v.into())));
+    quote!(#crate_ref::internal::construct::metadata(vec![#(#entries),*]))
+}
+
 #[derive(Default, Clone)]
 pub struct CommonAttr {
     pub doc: String,
@@ -57,6 +100,9 @@ impl CommonAttr {
                 Some(AttributeValue::Path(_)) => {
                     unreachable!("deprecated attribute can't be a path!")
                 }
+                Some(AttributeValue::Expr(expr)) => {
+                    return Err(syn::Error::new_spanned(expr, "expected string"))
+                }
                 Some(AttributeValue::Attribute { attr, .. }) => {
                     let since = attr
                         .iter()
@@ -1,29 +1,41 @@
 use proc_macro2::TokenStream;
 use quote::ToTokens;
-use syn::Result;
+use syn::{Expr, Result, Type};
 
 use crate::utils::{Attribute, Inflection};
 
-use super::CommonAttr;
+use super::{parse_meta, CommonAttr};
 
 #[derive(Default, Clone)]
 pub struct ContainerAttr {
     pub rename_all: Option<Inflection>,
     pub rename: Option<TokenStream>,
+    pub deny_unknown_fields: bool,
     pub tag: Option<String>,
     pub crate_name: Option<TokenStream>,
     pub inline: bool,
     pub remote: Option<TokenStream>,
     pub export: Option<bool>,
+    pub readonly: Option<bool>,
+    // The type from `#[serde(into = "...")]`. The container is serialized as this type.
+    pub into: Option<Type>,
+    // The type from `#[serde(from = "...")]` or `#[serde(try_from = "...")]`. The container is deserialized from this type.
+    pub from: Option<Type>,
+    pub example: Option<Expr>,
+    pub meta: Vec<(String, String)>,
     pub common: CommonAttr,
 
     // Struct ony (we pass it anyway so enums get nice errors)
     pub transparent: bool,
+
+    // Enum only
+    pub rename_all_fields: Option<Inflection>,
 }
 
 impl_parse! {
     ContainerAttr(attr, out) {
         "rename_all" => out.rename_all = out.rename_all.take().or(Some(attr.parse_inflection()?)),
+        "rename_all_fields" => out.rename_all_fields = out.rename_all_fields.take().or(Some(attr.parse_inflection()?)),
         "rename" => {
             let attr = attr.parse_string()?;
             out.rename = out.rename.take().or_else(|| Some(attr.to_token_stream()))
@@ -44,7 +56,13 @@ impl_parse! {
         "inline" => out.inline = attr.parse_bool().unwrap_or(true),
         "remote" => out.remote = out.remote.take().or(Some(attr.parse_path()?.to_token_stream())),
         "export" => out.export = out.export.take().or(Some(attr.parse_bool().unwrap_or(true))),
+        "readonly" => out.readonly = out.readonly.take().or(Some(attr.parse_bool().unwrap_or(true))),
         "transparent" => out.transparent = attr.parse_bool().unwrap_or(true),
+        "into" => out.into = out.into.take().or(Some(attr.parse_type()?)),
+        "from" | "try_from" => out.from = out.from.take().or(Some(attr.parse_type()?)),
+        "meta" => parse_meta(&attr, &mut out.meta)?,
+        "example" => out.example = out.example.take().or(Some(attr.parse_expr()?)),
+        "deny_unknown_fields" => out.deny_unknown_fields = attr.parse_bool().unwrap_or(true),
     }
 }
 
@@ -1,19 +1,36 @@
 use proc_macro2::TokenStream;
 use quote::ToTokens;
-use syn::{Result, Type, TypePath};
+use syn::{Expr, Ident, Path, Result, Type, TypePath};
 
-use crate::utils::Attribute;
+use crate::utils::{Attribute, AttributeValue};
 
-use super::CommonAttr;
+use super::{parse_meta, CommonAttr};
 
 #[derive(Default)]
 pub struct FieldAttr {
     pub rename: Option<TokenStream>,
     pub r#type: Option<Type>,
     pub inline: bool,
-    pub skip: bool,
+    pub skip_serializing: bool,
+    pub skip_deserializing: bool,
     pub optional: bool,
+    pub default: bool,
+    // The function from `#[serde(default = "path")]`.
+    pub default_fn: Option<Path>,
+    pub skip_serializing_if: bool,
     pub flatten: bool,
+    pub alias: Vec<String>,
+    pub readonly: Option<bool>,
+    // The `with`, `serialize_with` or `deserialize_with` attribute used if the field has a custom serializer.
+    pub custom_serializer: Option<Ident>,
+    pub format: Option<String>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub pattern: Option<String>,
+    pub example: Option<Expr>,
+    pub meta: Vec<(String, String)>,
     pub common: CommonAttr,
 }
 
@@ -37,18 +54,109 @@ impl_parse! {
             path: attr.parse_path()?,
         }))),
         "inline" => out.inline = attr.parse_bool().unwrap_or(true),
-        "skip" => out.skip = attr.parse_bool().unwrap_or(true),
-        "skip_serializing" => out.skip = true,
-        "skip_deserializing" => out.skip = true,
-        "skip_serializing_if" => out.optional = attr.parse_string()? == *"Option::is_none",
+        "skip" => {
+            let skip = attr.parse_bool().unwrap_or(true);
+            out.skip_serializing = skip;
+            out.skip_deserializing = skip;
+        },
+        "skip_serializing" => out.skip_serializing = true,
+        "skip_deserializing" => out.skip_deserializing = true,
+        "skip_serializing_if" => out.skip_serializing_if = attr.parse_string().is_ok(),
         // Specta only attribute
         "optional" => out.optional = attr.parse_bool().unwrap_or(true),
-        "default" => out.optional = attr.parse_bool().unwrap_or(true),
+        "default" => match attr.parse_string() {
+            Ok(path) => {
+                out.default = true;
+                out.default_fn = Some(syn::parse_str(&path).map_err(|_| {
+                    syn::Error::new(attr.value_span(), "specta: expected string literal containing a path. Eg. `\"default_value\"`")
+                })?);
+            }
+            Err(_) => out.default = attr.parse_bool().unwrap_or(true),
+        },
         "flatten" => out.flatten = attr.parse_bool().unwrap_or(true),
+        "alias" => out.alias.push(attr.parse_string()?),
+        // Specta only attribute
+        "format" => out.format = out.format.take().or(Some(attr.parse_string()?)),
+        "min" => out.min = out.min.take().or(Some(attr.parse_f64()?)),
+        "max" => out.max = out.max.take().or(Some(attr.parse_f64()?)),
+        "length" => match &attr.value {
+            Some(AttributeValue::Attribute { attr, .. }) => {
+                for attr in attr {
+                    match attr.key.to_string().as_str() {
+                        "min" => out.min_length = out.min_length.take().or(Some(attr.parse_usize()?)),
+                        "max" => out.max_length = out.max_length.take().or(Some(attr.parse_usize()?)),
+                        "equal" => {
+                            let length = attr.parse_usize()?;
+                            out.min_length = out.min_length.take().or(Some(length));
+                            out.max_length = out.max_length.take().or(Some(length));
+                        }
+                        _ => return Err(syn::Error::new(attr.key.span(), "specta: expected `min`, `max` or `equal`. Eg. `length(min = 1, max = 64)`")),
+                    }
+                }
+            }
+            _ => return Err(syn::Error::new(attr.value_span(), "specta: expected `length(min = ..., max = ...)`")),
+        },
+        "meta" => parse_meta(&attr, &mut out.meta)?,
+        "example" => out.example = out.example.take().or(Some(attr.parse_expr()?)),
+        "pattern" => out.pattern = out.pattern.take().or(Some(attr.parse_string()?)),
+        "with" | "serialize_with" | "deserialize_with" => out.custom_serializer = Some(attr.key.clone()),
+        "readonly" => out.readonly = out.readonly.take().or(Some(attr.parse_bool().unwrap_or(true))),
     }
 }
 
 impl FieldAttr {
+    /// Construct the `FieldConstraints` from the `min`, `max`, `length` and `pattern` attributes.
+    pub fn constraints_as_tokens(&self, crate_ref: &TokenStream) -> TokenStream {
+        fn option<T: ToTokens>(v: &Option<T>) -> TokenStream {
+            match v {
+                Some(v) => quote::quote!(Some(#v)),
+                None => quote::quote!(None),
+            }
+        }
+
+        let min = option(&self.min);
+        let max = option(&self.max);
+        let min_length = option(&self.min_length);
+        let max_length = option(&self.max_length);
+        let pattern = match &self.pattern {
+            Some(pattern) => quote::quote!(Some(std::borrow::Cow::Borrowed(#pattern))),
+            None => quote::quote!(None),
+        };
+
+        quote::quote!(#crate_ref::internal::construct::field_constraints(#min, #max, #min_length, #max_length, #pattern))
+    }
+
+    /// Capture the default value of a `#[serde(default)]` field. `ty` must be the real type of the field, not the type override.
+    pub fn default_value_as_tokens(&self, crate_ref: &TokenStream, ty: &Type) -> TokenStream {
+        // A custom serializer means the field's `Serialize` impl doesn't match its wire format.
+        if !self.default || self.custom_serializer.is_some() {
+            return quote::quote!(None);
+        }
+
+        let probe = match &self.default_fn {
+            Some(path) => {
+                quote::quote!(#crate_ref::internal::default_value::DefaultFnProbe::<#ty>(#path))
+            }
+            None => quote::quote!(#crate_ref::internal::default_value::DefaultProbe::<#ty>::new()),
+        };
+
+        quote::quote!({
+            #[allow(unused_imports)]
+            use #crate_ref::internal::default_value::{ViaFallback as _, ViaSerialize as _};
+            (&&#probe).default_value()
+        })
+    }
+
+    /// Apply the `#[specta(format = "...")]` attribute to an expression producing the field's `DataType`.
+    pub fn with_format(&self, crate_ref: &TokenStream, ty: TokenStream) -> TokenStream {
+        match &self.format {
+            Some(format) => {
+                quote::quote!(#crate_ref::internal::construct::string_format(#ty, #format))
+            }
+            None => ty,
+        }
+    }
+
     pub fn from_attrs(attrs: &mut Vec<Attribute>) -> Result<Self> {
         let mut result = Self::default();
         result.common = CommonAttr::from_attrs(attrs)?;
@@ -1,17 +1,23 @@
 use proc_macro2::TokenStream;
 use quote::ToTokens;
-use syn::Result;
+use syn::{Ident, Result};
 
 use crate::utils::{Attribute, Inflection};
 
-use super::CommonAttr;
+use super::{parse_meta, CommonAttr};
 
 #[derive(Default)]
 pub struct VariantAttr {
     pub rename_all: Option<Inflection>,
     pub rename: Option<TokenStream>,
-    pub skip: bool,
+    pub skip_serializing: bool,
+    pub skip_deserializing: bool,
+    pub other: bool,
+    pub alias: Vec<String>,
     pub inline: bool,
+    pub meta: Vec<(String, String)>,
+    // The `with`, `serialize_with` or `deserialize_with` attribute used if the variant has a custom serializer.
+    pub custom_serializer: Option<Ident>,
     pub common: CommonAttr,
 }
 
@@ -19,9 +25,17 @@ impl_parse! {
     VariantAttr(attr, out) {
         "rename_all" => out.rename_all = out.rename_all.take().or(Some(attr.parse_inflection()?)),
         "rename" => out.rename = out.rename.take().or(Some(attr.parse_string()?.to_token_stream())),
-        "skip" => out.skip = attr.parse_bool().unwrap_or(true),
-        "skip_serializing" => out.skip = true,
-        "skip_deserializing" => out.skip = true,
+        "skip" => {
+            let skip = attr.parse_bool().unwrap_or(true);
+            out.skip_serializing = skip;
+            out.skip_deserializing = skip;
+        },
+        "skip_serializing" => out.skip_serializing = true,
+        "skip_deserializing" => out.skip_deserializing = true,
+        "other" => out.other = attr.parse_bool().unwrap_or(true),
+        "meta" => parse_meta(&attr, &mut out.meta)?,
+        "alias" => out.alias.push(attr.parse_string()?),
+        "with" | "serialize_with" | "deserialize_with" => out.custom_serializer = Some(attr.key.clone()),
         "inline" => out.inline = attr.parse_bool().unwrap_or(true),
     }
 }
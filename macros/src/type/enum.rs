@@ -84,6 +84,16 @@ pub fn parse_enum(
                     }
                 }
 
+                // There is no way to override the type of a variant so a custom serializer can only be skipped.
+                if let Some(key) = &variant_attrs.custom_serializer {
+                    if !(variant_attrs.skip_serializing && variant_attrs.skip_deserializing) {
+                        return Err(syn::Error::new(
+                            key.span(),
+                            format!("specta: `#[serde({key})]` changes the type of the variant so it can't be inferred. Use `#[specta(skip)]` to skip it or move the custom serializer onto its fields."),
+                        ));
+                    }
+                }
+
                 Ok((v, variant_attrs))
             })
             .collect::<syn::Result<Vec<_>>>()?
@@ -107,11 +117,24 @@ pub fn parse_enum(
                             .iter()
                             .map(|field| {
                                 let field_attrs = decode_field_attrs(field)?;
+                                let format_gen = field_attrs.with_format(crate_ref, quote!(gen));
+                                let constraints = field_attrs.constraints_as_tokens(crate_ref);
+                                let example = example_as_tokens(crate_ref, &field_attrs.example);
+                                let metadata = metadata_as_tokens(crate_ref, &field_attrs.meta);
+                                let default_value = field_attrs.default_value_as_tokens(crate_ref, &field.ty);
                                 let deprecated = field_attrs.common.deprecated_as_tokens(crate_ref);
                                 let field_ty = field_attrs.r#type.as_ref().unwrap_or(&field.ty);
-                                let skip = field_attrs.skip;
+                                let skip_serializing = field_attrs.skip_serializing;
+                                let skip_deserializing = field_attrs.skip_deserializing;
                                 let optional = field_attrs.optional;
+                                let default = field_attrs.default;
+                                let skip_serializing_if = field_attrs.skip_serializing_if;
                                 let flatten = field_attrs.flatten;
+                                let aliases = &field_attrs.alias;
+                                let readonly = match field_attrs.readonly.or(container_attrs.readonly) {
+                                    Some(readonly) => quote!(Some(#readonly)),
+                                    None => quote!(None),
+                                };
                                 let doc = field_attrs.common.doc;
 
                                 let generic_vars = construct_datatype(
@@ -123,15 +146,24 @@ pub fn parse_enum(
                                 )?;
 
                                 Ok(quote!(#crate_ref::internal::construct::field(
-                                    #skip,
+                                    #skip_serializing,
+                                    #skip_deserializing,
                                     #optional,
+                                    #default,
+                                    #default_value,
+                                    #skip_serializing_if,
                                     #flatten,
+                                    vec![#(#aliases.into()),*],
+                                    #readonly,
+                                    #constraints,
+                                    #example,
                                     #deprecated,
+                                    #metadata,
                                     #doc.into(),
                                     {
                                         #generic_vars
 
-                                        gen
+                                        #format_gen
                                     }
                                 )))
                             })
@@ -147,6 +179,11 @@ pub fn parse_enum(
                         .iter()
                         .map(|field| {
                             let field_attrs = decode_field_attrs(field)?;
+                            let format_gen = field_attrs.with_format(crate_ref, quote!(gen));
+                            let constraints = field_attrs.constraints_as_tokens(crate_ref);
+                            let example = example_as_tokens(crate_ref, &field_attrs.example);
+                            let metadata = metadata_as_tokens(crate_ref, &field_attrs.meta);
+                            let default_value = field_attrs.default_value_as_tokens(crate_ref, &field.ty);
 
                             let field_ty = field_attrs.r#type.as_ref().unwrap_or(&field.ty);
 
@@ -161,7 +198,7 @@ pub fn parse_enum(
                             let field_ident_str =
                                 unraw_raw_ident(field.ident.as_ref().unwrap());
 
-                            let field_name = match (field_attrs.rename, attrs.rename_all) {
+                            let field_name = match (field_attrs.rename, attrs.rename_all.or(container_attrs.rename_all_fields)) {
                                 (Some(name), _) => name,
                                 (_, Some(inflection)) => {
                                     let name = inflection.apply(&field_ident_str);
@@ -170,21 +207,38 @@ pub fn parse_enum(
                                 (_, _) => quote::quote!(#field_ident_str),
                             };
                             let deprecated = field_attrs.common.deprecated_as_tokens(crate_ref);
-                            let skip = field_attrs.skip;
+                            let skip_serializing = field_attrs.skip_serializing;
+                            let skip_deserializing = field_attrs.skip_deserializing;
                             let optional = field_attrs.optional;
+                            let default = field_attrs.default;
+                            let skip_serializing_if = field_attrs.skip_serializing_if;
                             let flatten = field_attrs.flatten;
+                            let aliases = &field_attrs.alias;
+                            let readonly = match field_attrs.readonly.or(container_attrs.readonly) {
+                                Some(readonly) => quote!(Some(#readonly)),
+                                None => quote!(None),
+                            };
                             let doc = field_attrs.common.doc;
 
                             Ok(quote!((#field_name.into(), #crate_ref::internal::construct::field(
-                                #skip,
+                                #skip_serializing,
+                                #skip_deserializing,
                                 #optional,
+                                #default,
+                                #default_value,
+                                #skip_serializing_if,
                                 #flatten,
+                                vec![#(#aliases.into()),*],
+                                #readonly,
+                                #constraints,
+                                #example,
                                 #deprecated,
+                                #metadata,
                                 #doc.into(),
                                 {
                                     #generic_vars
 
-                                    gen
+                                    #format_gen
                                 },
                             ))))
                         })
@@ -195,12 +249,22 @@ pub fn parse_enum(
                 };
 
                 let deprecated = attrs.common.deprecated_as_tokens(crate_ref);
-                let skip = attrs.skip;
+                let skip_serializing = attrs.skip_serializing;
+                let skip_deserializing = attrs.skip_deserializing;
+                let other = attrs.other;
+                let aliases = &attrs.alias;
+                let metadata = metadata_as_tokens(crate_ref, &attrs.meta);
                 let doc = attrs.common.doc;
-                Ok(quote!((#variant_name_str.into(), #crate_ref::internal::construct::enum_variant(#skip, #deprecated, #doc.into(), #inner))))
+                Ok(quote!((#variant_name_str.into(), #crate_ref::internal::construct::enum_variant(#skip_serializing, #skip_deserializing, #other, vec![#(#aliases.into()),*], #deprecated, #metadata, #doc.into(), #inner))))
             })
             .collect::<syn::Result<Vec<_>>>()?;
 
+    let deny_unknown_fields = container_attrs.deny_unknown_fields;
+    let readonly = match container_attrs.readonly {
+        Some(readonly) => quote!(Some(#readonly)),
+        None => quote!(None),
+    };
+
     let (repr, can_flatten) = match repr {
         Tagged::Untagged => (
             quote!(#crate_ref::EnumRepr::Untagged),
@@ -229,7 +293,7 @@ pub fn parse_enum(
     };
 
     Ok((
-        quote!(#crate_ref::DataType::Enum(#crate_ref::internal::construct::r#enum(#name.into(), #repr, vec![#(#definition_generics),*], vec![#(#variant_types),*]))),
+        quote!(#crate_ref::DataType::Enum(#crate_ref::internal::construct::r#enum(#name.into(), #repr, vec![#(#definition_generics),*], vec![#(#variant_types),*], #deny_unknown_fields, #readonly))),
         quote!({
             let generics = vec![#(#reference_generics),*];
             #crate_ref::reference::reference::<Self>(opts, &generics, #crate_ref::internal::construct::data_type_reference(
@@ -3,14 +3,15 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use r#enum::parse_enum;
 use r#struct::parse_struct;
-use syn::{parse_macro_input, Data, DeriveInput};
+use syn::{parse_macro_input, Data, DeriveInput, GenericParam};
 
 use generics::impl_heading;
 
 use crate::utils::{parse_attrs, unraw_raw_ident, AttributeValue};
 
 use self::generics::{
-    add_type_to_where_clause, generics_with_ident_and_bounds_only, generics_with_ident_only,
+    add_type_to_where_clause, construct_datatype, generics_with_ident_and_bounds_only,
+    generics_with_ident_only,
 };
 
 pub(crate) mod attr;
@@ -60,6 +61,69 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
         )),
     }?;
 
+    // Serde will (de)serialize the container as a completely different type so we export that type instead.
+    let generic_idents = generics
+        .params
+        .iter()
+        .enumerate()
+        .filter_map(|(i, p)| match p {
+            GenericParam::Type(t) => Some((i, &t.ident)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let conversion = |ident: &str, ty| {
+        construct_datatype(
+            format_ident!("{}", ident),
+            ty,
+            &generic_idents,
+            &crate_ref,
+            container_attrs.inline,
+        )
+    };
+    let (inlines, can_flatten) = match (&container_attrs.into, &container_attrs.from) {
+        // The container has a different shape in each direction so the exporter picks one based on the `SerdeMode`.
+        (Some(into), Some(from)) => {
+            let into = conversion("into", into)?;
+            let from = conversion("from", from)?;
+
+            (
+                quote!({
+                    #into
+                    #from
+
+                    #crate_ref::DataType::Conversion(Box::new((into, from)))
+                }),
+                false,
+            )
+        }
+        // Serde only converts in one direction, the other uses the container's own shape.
+        (Some(into), None) => {
+            let into = conversion("into", into)?;
+
+            (
+                quote!({
+                    #into
+
+                    #crate_ref::DataType::Conversion(Box::new((into, #inlines)))
+                }),
+                false,
+            )
+        }
+        (None, Some(from)) => {
+            let from = conversion("from", from)?;
+
+            (
+                quote!({
+                    #from
+
+                    #crate_ref::DataType::Conversion(Box::new((#inlines, from)))
+                }),
+                false,
+            )
+        }
+        (None, None) => (inlines, can_flatten),
+    };
+
     // The expectation is that when an attribute is processed it will be removed so if any are left over we know they are invalid
     // but we only throw errors for Specta-specific attributes so we don't continually break other attributes.
     if let Some(attrs) = attrs.iter().find(|attr| attr.key == "specta") {
@@ -126,9 +190,13 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
         None => quote!(None),
     };
     let deprecated = container_attrs.common.deprecated_as_tokens(&crate_ref);
+    let example = example_as_tokens(&crate_ref, &container_attrs.example);
+    let metadata = metadata_as_tokens(&crate_ref, &container_attrs.meta);
 
     let sid = quote!(#crate_ref::internal::construct::sid(#name, concat!("::", module_path!(), ":", line!(), ":", column!())));
     let impl_location = quote!(#crate_ref::internal::construct::impl_location(concat!(file!(), ":", line!(), ":", column!())));
+    let rust_name = unraw_raw_ident(&format_ident!("{}", raw_ident.to_string()));
+    let stable_id = quote!(#crate_ref::internal::construct::stable_id(concat!(module_path!(), "::", #rust_name)));
 
     Ok(quote! {
         const _: () = {
@@ -160,8 +228,12 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
                         #name.into(),
                         #comments.into(),
                         #deprecated,
+                        #example,
+                        #metadata,
                         SID,
                         IMPL_LOCATION,
+                        #stable_id,
+                        module_path!(),
                         #should_export,
                         <Self as #crate_ref::Type>::inline(opts, generics)
                     )
@@ -32,6 +32,18 @@ pub fn decode_field_attrs(field: &Field) -> syn::Result<FieldAttr> {
         }
     }
 
+    // A custom serializer can change the type entirely so we can't trust the Rust type.
+    if let Some(key) = &field_attrs.custom_serializer {
+        if field_attrs.r#type.is_none()
+            && !(field_attrs.skip_serializing && field_attrs.skip_deserializing)
+        {
+            return Err(syn::Error::new(
+                key.span(),
+                format!("specta: `#[serde({key})]` changes the type of the field so it can't be inferred. Use `#[specta(type = ...)]` to specify the type it's serialized as or `#[specta(skip)]` to skip it."),
+            ));
+        }
+    }
+
     Ok(field_attrs)
 }
 
@@ -96,6 +108,7 @@ pub fn parse_struct(
             .next()
             .expect("unreachable: we just checked this!");
         let field_attrs = decode_field_attrs(field)?;
+        let format_ty = field_attrs.with_format(crate_ref, quote!(ty));
 
         let field_ty = field_attrs.r#type.as_ref().unwrap_or(&field.ty);
 
@@ -110,7 +123,7 @@ pub fn parse_struct(
         quote!({
             #ty
 
-            ty
+            #format_ty
         })
     } else {
         let fields = match &data.fields {
@@ -119,6 +132,11 @@ pub fn parse_struct(
                 .iter()
                 .map(|field| {
                     let field_attrs = decode_field_attrs(field)?;
+                    let format_ty = field_attrs.with_format(crate_ref, quote!(ty));
+                    let constraints = field_attrs.constraints_as_tokens(crate_ref);
+                    let example = example_as_tokens(crate_ref, &field_attrs.example);
+                    let metadata = metadata_as_tokens(crate_ref, &field_attrs.meta);
+                    let default_value = field_attrs.default_value_as_tokens(crate_ref, &field.ty);
                     let field_ty = field_attrs.r#type.as_ref().unwrap_or(&field.ty);
 
                     let ty = construct_datatype(
@@ -139,8 +157,16 @@ pub fn parse_struct(
 
                     let deprecated = field_attrs.common.deprecated_as_tokens(crate_ref);
                     let optional = field_attrs.optional;
+                    let default = field_attrs.default;
+                    let skip_serializing_if = field_attrs.skip_serializing_if;
                     let flatten = field_attrs.flatten;
-                    let skip = field_attrs.skip;
+                    let aliases = &field_attrs.alias;
+                    let readonly = match field_attrs.readonly.or(container_attrs.readonly) {
+                        Some(readonly) => quote!(Some(#readonly)),
+                        None => quote!(None),
+                    };
+                    let skip_serializing = field_attrs.skip_serializing;
+                    let skip_deserializing = field_attrs.skip_deserializing;
                     let doc = field_attrs.common.doc;
 
                     let parent_inline = container_attrs
@@ -163,21 +189,30 @@ pub fn parse_struct(
                                 type_map: opts.type_map
                             }, &generics);
 
-                            ty
+                            #format_ty
                         }
                     } else {
                         quote! {
                             #ty
 
-                            ty
+                            #format_ty
                         }
                     };
 
                     Ok(quote!((#field_name.into(), #crate_ref::internal::construct::field(
-                        #skip,
+                        #skip_serializing,
+                        #skip_deserializing,
                         #optional,
+                        #default,
+                        #default_value,
+                        #skip_serializing_if,
                         #flatten,
+                        vec![#(#aliases.into()),*],
+                        #readonly,
+                        #constraints,
+                        #example,
                         #deprecated,
+                        #metadata,
                         #doc.into(),
                         {
                             #ty
@@ -199,6 +234,11 @@ pub fn parse_struct(
                     .iter()
                     .map(|field| {
                         let field_attrs = decode_field_attrs(field)?;
+                        let format_gen = field_attrs.with_format(crate_ref, quote!(gen));
+                        let constraints = field_attrs.constraints_as_tokens(crate_ref);
+                        let example = example_as_tokens(crate_ref, &field_attrs.example);
+                        let metadata = metadata_as_tokens(crate_ref, &field_attrs.meta);
+                        let default_value = field_attrs.default_value_as_tokens(crate_ref, &field.ty);
                         let field_ty = field_attrs.r#type.as_ref().unwrap_or(&field.ty);
 
                         let generic_vars = construct_datatype(
@@ -211,14 +251,22 @@ pub fn parse_struct(
 
                         let deprecated = field_attrs.common.deprecated_as_tokens(crate_ref);
                         let optional = field_attrs.optional;
+                        let default = field_attrs.default;
+                        let skip_serializing_if = field_attrs.skip_serializing_if;
                         let flatten = field_attrs.flatten;
-                        let skip = field_attrs.skip;
+                        let aliases = &field_attrs.alias;
+                        let readonly = match field_attrs.readonly.or(container_attrs.readonly) {
+                            Some(readonly) => quote!(Some(#readonly)),
+                            None => quote!(None),
+                        };
+                        let skip_serializing = field_attrs.skip_serializing;
+                        let skip_deserializing = field_attrs.skip_deserializing;
                         let doc = field_attrs.common.doc;
 
                         Ok(quote!({
                             #generic_vars
 
-                            #crate_ref::internal::construct::field(#skip, #optional, #flatten, #deprecated, #doc.into(), gen)
+                            #crate_ref::internal::construct::field(#skip_serializing, #skip_deserializing, #optional, #default, #default_value, #skip_serializing_if, #flatten, vec![#(#aliases.into()),*], #readonly, #constraints, #example, #deprecated, #metadata, #doc.into(), #format_gen)
                         }))
                     })
                     .collect::<syn::Result<Vec<TokenStream>>>()?;
@@ -228,7 +276,13 @@ pub fn parse_struct(
             Fields::Unit => quote!(#crate_ref::internal::construct::struct_unit()),
         };
 
-        quote!(#crate_ref::DataType::Struct(#crate_ref::internal::construct::r#struct(#name.into(), vec![#(#definition_generics),*], #fields)))
+        let deny_unknown_fields = container_attrs.deny_unknown_fields;
+        let readonly = match container_attrs.readonly {
+            Some(readonly) => quote!(Some(#readonly)),
+            None => quote!(None),
+        };
+
+        quote!(#crate_ref::DataType::Struct(#crate_ref::internal::construct::r#struct(#name.into(), vec![#(#definition_generics),*], #fields, #deny_unknown_fields, #readonly)))
     };
 
     let category = if container_attrs.inline {
@@ -2,10 +2,10 @@ use proc_macro2::Span;
 use syn::{
     ext::IdentExt,
     parenthesized,
-    parse::{Parse, ParseStream},
+    parse::{discouraged::Speculative, Parse, ParseStream},
     spanned::Spanned,
     token::Paren,
-    Ident, Lit, Path, Result, Token,
+    Expr, Ident, Lit, LitFloat, LitInt, Path, Result, Token, Type,
 };
 
 #[derive(Clone)]
@@ -17,6 +17,8 @@ pub enum AttributeValue {
     Path(Path),
     /// A nested attribute. Eg. the `deprecated(note = "some note") in `#[specta(deprecated(note = "some note"))]`
     Attribute { span: Span, attr: Vec<Attribute> },
+    /// Any other Rust expression. Eg. `#[specta(example = Point { x: 1, y: 2 })]`
+    Expr(Expr),
 }
 
 impl AttributeValue {
@@ -25,12 +27,43 @@ impl AttributeValue {
             Self::Lit(lit) => lit.span(),
             Self::Path(path) => path.span(),
             Self::Attribute { span, .. } => *span,
+            Self::Expr(expr) => expr.span(),
         }
     }
 }
 
 impl Parse for AttributeValue {
     fn parse(input: ParseStream) -> Result<Self> {
+        // We only fallback to parsing an expression if it's not a literal or path so existing attributes behave the same.
+        let fork = input.fork();
+        if let Ok(value) = Self::parse_lit_or_path(&fork) {
+            if fork.is_empty() || fork.peek(Token![,]) {
+                input.advance_to(&fork);
+                return Ok(value);
+            }
+        }
+
+        Ok(Self::Expr(input.parse()?))
+    }
+}
+
+impl AttributeValue {
+    fn parse_lit_or_path(input: ParseStream) -> Result<Self> {
+        // Negative numbers. Eg. `#[specta(min = -1)]`
+        if input.peek(Token![-]) && input.peek2(Lit) {
+            input.parse::<Token![-]>()?;
+            return Ok(Self::Lit(match input.parse()? {
+                Lit::Int(lit) => Lit::Int(LitInt::new(&format!("-{lit}"), lit.span())),
+                Lit::Float(lit) => Lit::Float(LitFloat::new(&format!("-{lit}"), lit.span())),
+                lit => {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "specta: expected number literal after `-`",
+                    ))
+                }
+            }));
+        }
+
         Ok(match input.peek(Lit) {
             true => Self::Lit(input.parse()?),
             false => Self::Path(input.parse()?),
@@ -76,6 +109,27 @@ impl Attribute {
         }
     }
 
+    pub fn parse_f64(&self) -> Result<f64> {
+        match &self.value {
+            Some(AttributeValue::Lit(Lit::Int(int))) => int.base10_parse(),
+            Some(AttributeValue::Lit(Lit::Float(float))) => float.base10_parse(),
+            _ => Err(syn::Error::new(
+                self.value_span(),
+                "specta: expected number literal. Eg. `42` or `-1.5`",
+            )),
+        }
+    }
+
+    pub fn parse_usize(&self) -> Result<usize> {
+        match &self.value {
+            Some(AttributeValue::Lit(Lit::Int(int))) => int.base10_parse(),
+            _ => Err(syn::Error::new(
+                self.value_span(),
+                "specta: expected integer literal. Eg. `42`",
+            )),
+        }
+    }
+
     pub fn parse_path(&self) -> Result<Path> {
         match &self.value {
             Some(AttributeValue::Path(path)) => Ok(path.clone()),
@@ -86,6 +140,29 @@ impl Attribute {
         }
     }
 
+    /// Parse any Rust expression. Eg. `"hello"`, `SOME_CONST` or `Point { x: 1, y: 2 }`
+    pub fn parse_expr(&self) -> Result<Expr> {
+        match &self.value {
+            Some(AttributeValue::Lit(lit)) => Ok(syn::parse_quote!(#lit)),
+            Some(AttributeValue::Path(path)) => Ok(syn::parse_quote!(#path)),
+            Some(AttributeValue::Expr(expr)) => Ok(expr.clone()),
+            _ => Err(syn::Error::new(
+                self.value_span(),
+                "specta: expected expression. Eg. `42` or `Point { x: 1, y: 2 }`",
+            )),
+        }
+    }
+
+    /// Parse a type from a string literal. Eg. `"Vec<String>"`
+    pub fn parse_type(&self) -> Result<Type> {
+        syn::parse_str(&self.parse_string()?).map_err(|_| {
+            syn::Error::new(
+                self.value_span(),
+                "specta: expected string literal containing a type. Eg. `\"String\"`",
+            )
+        })
+    }
+
     pub fn parse_inflection(&self) -> Result<Inflection> {
         match &self.value {
             Some(AttributeValue::Lit(Lit::Str(lit))) => {
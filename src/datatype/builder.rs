@@ -0,0 +1,584 @@
+//! Builders for constructing types at runtime without the [`Type`](crate::Type) macro. Eg. when generating types from a database schema.
+
+use std::{borrow::Cow, panic::Location};
+
+use crate::{
+    DataType, DeprecatedType, EnumRepr, EnumType, EnumVariant, EnumVariants, Field, GenericType,
+    ImplLocation, NamedDataType, NamedDataTypeExt, NamedFields, SpectaID, StableID, StructFields,
+    StructType, UnnamedFields,
+};
+
+/// Builder for a [`Field`]. Create one with [`Field::builder`].
+#[derive(Debug, Clone)]
+pub struct FieldBuilder(Field);
+
+impl Field {
+    pub fn builder(ty: impl Into<DataType>) -> FieldBuilder {
+        FieldBuilder(Field::new(ty.into()))
+    }
+}
+
+impl FieldBuilder {
+    /// Mark the field as optional. Equivalent to `#[specta(optional)]`.
+    pub fn optional(mut self) -> Self {
+        self.0.optional = true;
+        self
+    }
+
+    /// Mark the field as missing when deserializing is allowed. Equivalent to `#[serde(default)]`.
+    pub fn default(mut self) -> Self {
+        self.0.default = true;
+        self
+    }
+
+    /// The value of the field when it's missing while deserializing. Implies [`FieldBuilder::default`].
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn default_value(mut self, value: serde_json::Value) -> Self {
+        self.0.default = true;
+        self.0.default_value = Some(value);
+        self
+    }
+
+    /// Equivalent to `#[serde(skip_serializing_if = "...")]`.
+    pub fn skip_serializing_if(mut self) -> Self {
+        self.0.skip_serializing_if = true;
+        self
+    }
+
+    /// Equivalent to `#[serde(skip)]`.
+    pub fn skip(self) -> Self {
+        self.skip_serializing().skip_deserializing()
+    }
+
+    /// Equivalent to `#[serde(skip_serializing)]`.
+    pub fn skip_serializing(mut self) -> Self {
+        self.0.skip_serializing = true;
+        self
+    }
+
+    /// Equivalent to `#[serde(skip_deserializing)]`.
+    pub fn skip_deserializing(mut self) -> Self {
+        self.0.skip_deserializing = true;
+        self
+    }
+
+    /// Equivalent to `#[serde(flatten)]`.
+    pub fn flatten(mut self) -> Self {
+        self.0.flatten = true;
+        self
+    }
+
+    /// Equivalent to `#[serde(alias = "...")]`. Can be called multiple times.
+    pub fn alias(mut self, alias: impl Into<Cow<'static, str>>) -> Self {
+        self.0.aliases.push(alias.into());
+        self
+    }
+
+    /// Equivalent to `#[specta(readonly)]`.
+    pub fn readonly(mut self, readonly: bool) -> Self {
+        self.0.readonly = Some(readonly);
+        self
+    }
+
+    /// Equivalent to `#[specta(min = ...)]`.
+    pub fn min(mut self, min: f64) -> Self {
+        self.0.constraints.min = Some(min);
+        self
+    }
+
+    /// Equivalent to `#[specta(max = ...)]`.
+    pub fn max(mut self, max: f64) -> Self {
+        self.0.constraints.max = Some(max);
+        self
+    }
+
+    /// Equivalent to `#[specta(length(min = ...))]`.
+    pub fn min_length(mut self, min_length: usize) -> Self {
+        self.0.constraints.min_length = Some(min_length);
+        self
+    }
+
+    /// Equivalent to `#[specta(length(max = ...))]`.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.0.constraints.max_length = Some(max_length);
+        self
+    }
+
+    /// Equivalent to `#[specta(pattern = "...")]`.
+    pub fn pattern(mut self, pattern: impl Into<Cow<'static, str>>) -> Self {
+        self.0.constraints.pattern = Some(pattern.into());
+        self
+    }
+
+    /// Equivalent to `#[specta(example = ...)]`.
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn example(mut self, example: serde_json::Value) -> Self {
+        self.0.example = Some(example);
+        self
+    }
+
+    pub fn docs(mut self, docs: impl Into<Cow<'static, str>>) -> Self {
+        self.0.docs = docs.into();
+        self
+    }
+
+    pub fn deprecated(mut self, deprecated: DeprecatedType) -> Self {
+        self.0.deprecated = Some(deprecated);
+        self
+    }
+
+    /// Equivalent to `#[specta(meta(key = "value"))]`.
+    pub fn meta(
+        mut self,
+        key: impl Into<Cow<'static, str>>,
+        value: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.0.metadata.insert(key, value);
+        self
+    }
+
+    pub fn build(self) -> Field {
+        self.0
+    }
+}
+
+impl From<FieldBuilder> for Field {
+    fn from(builder: FieldBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<DataType> for Field {
+    fn from(ty: DataType) -> Self {
+        Field::new(ty)
+    }
+}
+
+/// Builder for [`NamedFields`]. Create one with [`NamedFields::builder`].
+#[derive(Debug, Clone)]
+pub struct NamedFieldsBuilder(NamedFields);
+
+impl NamedFields {
+    pub fn builder() -> NamedFieldsBuilder {
+        NamedFieldsBuilder(NamedFields {
+            fields: vec![],
+            tag: None,
+        })
+    }
+}
+
+impl NamedFieldsBuilder {
+    pub fn field(mut self, name: impl Into<Cow<'static, str>>, field: impl Into<Field>) -> Self {
+        self.0.fields.push((name.into(), field.into()));
+        self
+    }
+
+    /// Equivalent to `#[serde(tag = "...")]` on a struct.
+    pub fn tag(mut self, tag: impl Into<Cow<'static, str>>) -> Self {
+        self.0.tag = Some(tag.into());
+        self
+    }
+
+    pub fn build(self) -> NamedFields {
+        self.0
+    }
+}
+
+impl From<NamedFieldsBuilder> for NamedFields {
+    fn from(builder: NamedFieldsBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Builder for [`UnnamedFields`]. Create one with [`UnnamedFields::builder`].
+#[derive(Debug, Clone)]
+pub struct UnnamedFieldsBuilder(UnnamedFields);
+
+impl UnnamedFields {
+    pub fn builder() -> UnnamedFieldsBuilder {
+        UnnamedFieldsBuilder(UnnamedFields { fields: vec![] })
+    }
+}
+
+impl UnnamedFieldsBuilder {
+    pub fn field(mut self, field: impl Into<Field>) -> Self {
+        self.0.fields.push(field.into());
+        self
+    }
+
+    pub fn build(self) -> UnnamedFields {
+        self.0
+    }
+}
+
+impl From<UnnamedFieldsBuilder> for UnnamedFields {
+    fn from(builder: UnnamedFieldsBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Builder for a [`StructType`]. Create one with [`StructType::builder`].
+///
+/// The struct is a unit struct until the first field is added. Adding a field with [`StructBuilder::field`] makes it a struct with named fields and [`StructBuilder::unnamed_field`] makes it a tuple struct.
+#[derive(Debug, Clone)]
+pub struct StructBuilder<F = ()> {
+    name: Cow<'static, str>,
+    generics: Vec<GenericType>,
+    fields: F,
+    deny_unknown_fields: bool,
+    readonly: Option<bool>,
+}
+
+impl StructType {
+    pub fn builder(name: impl Into<Cow<'static, str>>) -> StructBuilder {
+        StructBuilder {
+            name: name.into(),
+            generics: vec![],
+            fields: (),
+            deny_unknown_fields: false,
+            readonly: None,
+        }
+    }
+}
+
+impl<F> StructBuilder<F> {
+    pub fn generic(mut self, generic: impl Into<Cow<'static, str>>) -> Self {
+        self.generics.push(GenericType(generic.into()));
+        self
+    }
+
+    /// Equivalent to `#[serde(deny_unknown_fields)]`.
+    pub fn deny_unknown_fields(mut self) -> Self {
+        self.deny_unknown_fields = true;
+        self
+    }
+
+    /// Equivalent to `#[specta(readonly)]` on the container. This only applies to the tag, set [`FieldBuilder::readonly`] for each field.
+    pub fn readonly(mut self, readonly: bool) -> Self {
+        self.readonly = Some(readonly);
+        self
+    }
+
+    fn with_fields<G>(self, fields: G) -> StructBuilder<G> {
+        StructBuilder {
+            name: self.name,
+            generics: self.generics,
+            fields,
+            deny_unknown_fields: self.deny_unknown_fields,
+            readonly: self.readonly,
+        }
+    }
+
+    fn build_with(self, fields: impl FnOnce(F) -> StructFields) -> StructType {
+        StructType {
+            name: self.name,
+            generics: self.generics,
+            fields: fields(self.fields),
+            deny_unknown_fields: self.deny_unknown_fields,
+            readonly: self.readonly,
+        }
+    }
+}
+
+impl StructBuilder {
+    pub fn field(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        field: impl Into<Field>,
+    ) -> StructBuilder<NamedFields> {
+        self.with_fields(NamedFields::builder().build())
+            .field(name, field)
+    }
+
+    pub fn unnamed_field(self, field: impl Into<Field>) -> StructBuilder<UnnamedFields> {
+        self.with_fields(UnnamedFields::builder().build())
+            .unnamed_field(field)
+    }
+
+    pub fn build(self) -> StructType {
+        self.build_with(|()| StructFields::Unit)
+    }
+}
+
+impl StructBuilder<NamedFields> {
+    pub fn field(mut self, name: impl Into<Cow<'static, str>>, field: impl Into<Field>) -> Self {
+        self.fields.fields.push((name.into(), field.into()));
+        self
+    }
+
+    /// Equivalent to `#[serde(tag = "...")]`.
+    pub fn tag(mut self, tag: impl Into<Cow<'static, str>>) -> Self {
+        self.fields.tag = Some(tag.into());
+        self
+    }
+
+    pub fn build(self) -> StructType {
+        self.build_with(StructFields::Named)
+    }
+}
+
+impl StructBuilder<UnnamedFields> {
+    pub fn unnamed_field(mut self, field: impl Into<Field>) -> Self {
+        self.fields.fields.push(field.into());
+        self
+    }
+
+    pub fn build(self) -> StructType {
+        self.build_with(StructFields::Unnamed)
+    }
+}
+
+/// Builder for an [`EnumVariant`]. Create one with [`EnumVariant::builder`].
+#[derive(Debug, Clone)]
+pub struct EnumVariantBuilder(EnumVariant);
+
+impl EnumVariant {
+    /// Create a variant builder. Eg. `EnumVariant::builder(EnumVariants::Unit)` or `EnumVariant::builder(NamedFields::builder().field("a", field))`.
+    pub fn builder(inner: impl Into<EnumVariants>) -> EnumVariantBuilder {
+        EnumVariantBuilder(EnumVariant::new(inner.into()))
+    }
+}
+
+impl EnumVariantBuilder {
+    /// Equivalent to `#[serde(skip)]`.
+    pub fn skip(self) -> Self {
+        self.skip_serializing().skip_deserializing()
+    }
+
+    /// Equivalent to `#[serde(skip_serializing)]`.
+    pub fn skip_serializing(mut self) -> Self {
+        self.0.skip_serializing = true;
+        self
+    }
+
+    /// Equivalent to `#[serde(skip_deserializing)]`.
+    pub fn skip_deserializing(mut self) -> Self {
+        self.0.skip_deserializing = true;
+        self
+    }
+
+    /// Equivalent to `#[serde(other)]`.
+    pub fn other(mut self) -> Self {
+        self.0.other = true;
+        self
+    }
+
+    /// Equivalent to `#[serde(alias = "...")]`. Can be called multiple times.
+    pub fn alias(mut self, alias: impl Into<Cow<'static, str>>) -> Self {
+        self.0.aliases.push(alias.into());
+        self
+    }
+
+    pub fn docs(mut self, docs: impl Into<Cow<'static, str>>) -> Self {
+        self.0.docs = docs.into();
+        self
+    }
+
+    pub fn deprecated(mut self, deprecated: DeprecatedType) -> Self {
+        self.0.deprecated = Some(deprecated);
+        self
+    }
+
+    /// Equivalent to `#[specta(meta(key = "value"))]`.
+    pub fn meta(
+        mut self,
+        key: impl Into<Cow<'static, str>>,
+        value: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.0.metadata.insert(key, value);
+        self
+    }
+
+    pub fn build(self) -> EnumVariant {
+        self.0
+    }
+}
+
+impl From<EnumVariantBuilder> for EnumVariant {
+    fn from(builder: EnumVariantBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<EnumVariants> for EnumVariant {
+    fn from(inner: EnumVariants) -> Self {
+        EnumVariant::new(inner)
+    }
+}
+
+impl From<NamedFields> for EnumVariants {
+    fn from(fields: NamedFields) -> Self {
+        Self::Named(fields)
+    }
+}
+
+impl From<NamedFieldsBuilder> for EnumVariants {
+    fn from(builder: NamedFieldsBuilder) -> Self {
+        Self::Named(builder.build())
+    }
+}
+
+impl From<UnnamedFields> for EnumVariants {
+    fn from(fields: UnnamedFields) -> Self {
+        Self::Unnamed(fields)
+    }
+}
+
+impl From<UnnamedFieldsBuilder> for EnumVariants {
+    fn from(builder: UnnamedFieldsBuilder) -> Self {
+        Self::Unnamed(builder.build())
+    }
+}
+
+/// Builder for an [`EnumType`]. Create one with [`EnumType::builder`].
+#[derive(Debug, Clone)]
+pub struct EnumBuilder(EnumType);
+
+impl EnumType {
+    pub fn builder(name: impl Into<Cow<'static, str>>, repr: EnumRepr) -> EnumBuilder {
+        EnumBuilder(EnumType {
+            name: name.into(),
+            repr,
+            generics: vec![],
+            variants: vec![],
+            deny_unknown_fields: false,
+            readonly: None,
+        })
+    }
+}
+
+impl EnumBuilder {
+    pub fn generic(mut self, generic: impl Into<Cow<'static, str>>) -> Self {
+        self.0.generics.push(GenericType(generic.into()));
+        self
+    }
+
+    pub fn variant(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        variant: impl Into<EnumVariant>,
+    ) -> Self {
+        self.0.variants.push((name.into(), variant.into()));
+        self
+    }
+
+    /// Equivalent to `#[serde(deny_unknown_fields)]`.
+    pub fn deny_unknown_fields(mut self) -> Self {
+        self.0.deny_unknown_fields = true;
+        self
+    }
+
+    /// Equivalent to `#[specta(readonly)]` on the container. This only applies to the tags, set [`FieldBuilder::readonly`] for each field.
+    pub fn readonly(mut self, readonly: bool) -> Self {
+        self.0.readonly = Some(readonly);
+        self
+    }
+
+    pub fn build(self) -> EnumType {
+        self.0
+    }
+}
+
+/// Builder for a [`NamedDataType`]. Create one with [`NamedDataType::builder`].
+///
+/// Types created this way don't come from a Rust type so [`NamedDataType::ext`] is `None`, unless a [`sid`](Self::sid) is set so the type can be put in a [`TypeMap`](crate::TypeMap) and referenced.
+#[derive(Debug, Clone)]
+pub struct NamedDataTypeBuilder {
+    ty: NamedDataType,
+    sid: Option<(SpectaID, ImplLocation)>,
+    stable_id: Option<StableID>,
+    module_path: Cow<'static, str>,
+}
+
+impl NamedDataType {
+    pub fn builder(
+        name: impl Into<Cow<'static, str>>,
+        inner: impl Into<DataType>,
+    ) -> NamedDataTypeBuilder {
+        NamedDataTypeBuilder {
+            ty: inner.into().to_named(name),
+            sid: None,
+            stable_id: None,
+            module_path: Cow::Borrowed(""),
+        }
+    }
+}
+
+impl NamedDataTypeBuilder {
+    pub fn docs(mut self, docs: impl Into<Cow<'static, str>>) -> Self {
+        self.ty.docs = docs.into();
+        self
+    }
+
+    pub fn deprecated(mut self, deprecated: DeprecatedType) -> Self {
+        self.ty.deprecated = Some(deprecated);
+        self
+    }
+
+    /// Equivalent to `#[specta(example = ...)]`.
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn example(mut self, example: serde_json::Value) -> Self {
+        self.ty.example = Some(example);
+        self
+    }
+
+    /// Equivalent to `#[specta(meta(key = "value"))]`.
+    pub fn meta(
+        mut self,
+        key: impl Into<Cow<'static, str>>,
+        value: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.ty.metadata.insert(key, value);
+        self
+    }
+
+    /// The ID the type is stored under in a [`TypeMap`](crate::TypeMap). Use the same one in a [`DataTypeReference`](crate::DataTypeReference) to reference the type.
+    ///
+    /// The file this is called from is used as the [`impl_location`](NamedDataTypeExt::impl_location) of the type.
+    #[track_caller]
+    pub fn sid(mut self, sid: SpectaID) -> Self {
+        self.sid = Some((sid, ImplLocation(Location::caller().file())));
+        self
+    }
+
+    /// Defaults to the module path followed by the name of the type. Only used when a [`sid`](Self::sid) is set.
+    pub fn stable_id(mut self, stable_id: StableID) -> Self {
+        self.stable_id = Some(stable_id);
+        self
+    }
+
+    /// The module the type belongs to. Eg. `my_crate::api::users`. Only used when a [`sid`](Self::sid) is set.
+    pub fn module_path(mut self, module_path: impl Into<Cow<'static, str>>) -> Self {
+        self.module_path = module_path.into();
+        self
+    }
+
+    pub fn build(self) -> NamedDataType {
+        let Self {
+            mut ty,
+            sid,
+            stable_id,
+            module_path,
+        } = self;
+
+        if let Some((sid, impl_location)) = sid {
+            let stable_id = stable_id.unwrap_or_else(|| match module_path.is_empty() {
+                true => StableID::new(ty.name.clone()),
+                false => StableID::new(format!("{module_path}::{}", ty.name)),
+            });
+
+            ty.ext = Some(NamedDataTypeExt {
+                sid,
+                impl_location,
+                stable_id,
+                module_path,
+                export: None,
+            });
+        }
+
+        ty
+    }
+}
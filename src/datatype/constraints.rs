@@ -0,0 +1,50 @@
+use std::borrow::Cow;
+
+/// Validation rules for the value of a field.
+///
+/// These are set using `#[specta(min = 0, max = 100, length(min = 1, max = 64), pattern = "^[a-z]+$")]` and are only metadata. Specta doesn't validate anything.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldConstraints {
+    pub(crate) min: Option<f64>,
+    pub(crate) max: Option<f64>,
+    pub(crate) min_length: Option<usize>,
+    pub(crate) max_length: Option<usize>,
+    pub(crate) pattern: Option<Cow<'static, str>>,
+}
+
+impl FieldConstraints {
+    /// The inclusive minimum of a number.
+    pub fn min(&self) -> Option<f64> {
+        self.min
+    }
+
+    /// The inclusive maximum of a number.
+    pub fn max(&self) -> Option<f64> {
+        self.max
+    }
+
+    /// The minimum length of a string or list.
+    pub fn min_length(&self) -> Option<usize> {
+        self.min_length
+    }
+
+    /// The maximum length of a string or list.
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// A regular expression a string must match.
+    pub fn pattern(&self) -> Option<&Cow<'static, str>> {
+        self.pattern.as_ref()
+    }
+
+    /// Returns `true` if no constraints have been set.
+    pub fn is_empty(&self) -> bool {
+        self.min.is_none()
+            && self.max.is_none()
+            && self.min_length.is_none()
+            && self.max_length.is_none()
+            && self.pattern.is_none()
+    }
+}
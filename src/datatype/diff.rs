@@ -0,0 +1,569 @@
+//! Compare two [`TypeMap`]s to find changes to the wire format.
+//!
+//! This is useful in CI to catch changes which would break clients built against an older version of your types.
+//! The old [`TypeMap`] will usually come from a [`Schema`](crate::datatype::Schema) saved by a previous build.
+//!
+//! ```rust
+//! use specta::{datatype::diff::diff, SerdeMode, TypeMap};
+//!
+//! # let (old, new) = (TypeMap::default(), TypeMap::default());
+//! let changes = diff(&old, &new);
+//! for change in changes.iter().filter(|c| c.is_breaking(SerdeMode::Serialize)) {
+//!     println!("breaking change: {change}");
+//! }
+//! ```
+//!
+//! Named types are matched by their [`SpectaID`](crate::SpectaID), then their [`StableID`](crate::StableID) (which unlike the [`SpectaID`](crate::SpectaID) doesn't change when the type moves within a file) and then by name so a type which moved modules is still compared against its old definition.
+//! A [`StableID`](crate::StableID) which is shared by multiple types (see [`detect_stable_id_collisions`]) is ignored as it can't tell them apart.
+//! Unknown fields are assumed to be ignored by the reader so removing a field is never breaking for [`SerdeMode::Deserialize`].
+
+use std::{borrow::Cow, collections::BTreeSet, fmt};
+
+use crate::{
+    detect_stable_id_collisions, DataType, EnumRepr, EnumType, EnumVariant, EnumVariants, Field,
+    GenericType, NamedDataType, NamedFields, SerdeMode, StructFields, StructType, TypeMap,
+    UnnamedFields,
+};
+
+/// A single difference between two [`TypeMap`]s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    /// Where the change happened. Eg. `User.address.street` or `Event::Created.id`.
+    pub path: String,
+    pub kind: ChangeKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ChangeKind {
+    TypeAdded,
+    TypeRemoved,
+    /// The name of a named type changed. This doesn't affect the wire format.
+    TypeRenamed {
+        old: Cow<'static, str>,
+        new: Cow<'static, str>,
+    },
+    /// The generic parameters of a named type changed. This doesn't affect the wire format, the types used for them are compared where the type is referenced.
+    GenericsChanged {
+        old: Vec<GenericType>,
+        new: Vec<GenericType>,
+    },
+    /// The type was replaced with an incompatible one.
+    TypeChanged {
+        old: DataType,
+        new: DataType,
+    },
+    /// The type is now (or is no longer) nullable.
+    NullabilityChanged {
+        nullable: bool,
+    },
+    FieldAdded {
+        field: Field,
+    },
+    FieldRemoved {
+        field: Field,
+    },
+    /// A field was removed and a field with the same type was added in its place. Eg. `#[serde(rename = "...")]` was changed.
+    FieldRenamed {
+        old_name: Cow<'static, str>,
+        old: Field,
+        new: Field,
+    },
+    /// A field's optionality, skipping or flattening changed.
+    FieldModified {
+        old: Field,
+        new: Field,
+    },
+    VariantAdded {
+        variant: EnumVariant,
+    },
+    VariantRemoved {
+        variant: EnumVariant,
+    },
+    /// A variant was removed and a variant with the same fields was added in its place. Eg. `#[serde(rename = "...")]` was changed.
+    VariantRenamed {
+        old_name: Cow<'static, str>,
+        old: EnumVariant,
+        new: EnumVariant,
+    },
+    /// A variant changed between a unit, tuple or struct variant or it's skipping changed.
+    VariantModified {
+        old: EnumVariant,
+        new: EnumVariant,
+    },
+    /// The Serde representation of an enum changed. Eg. from externally to internally tagged.
+    ReprChanged {
+        old: EnumRepr,
+        new: EnumRepr,
+    },
+    /// The `#[serde(tag = "...")]` of a struct changed.
+    TagChanged {
+        old: Option<Cow<'static, str>>,
+        new: Option<Cow<'static, str>>,
+    },
+}
+
+impl Change {
+    /// Will this change break the other side of the wire when data is flowing in the given direction.
+    ///
+    /// - [`SerdeMode::Serialize`]: Data serialized with the new types is read by a client built against the old types.
+    /// - [`SerdeMode::Deserialize`]: Data produced by a client built against the old types is deserialized with the new types.
+    pub fn is_breaking(&self, mode: SerdeMode) -> bool {
+        match &self.kind {
+            ChangeKind::TypeAdded
+            | ChangeKind::TypeRenamed { .. }
+            | ChangeKind::GenericsChanged { .. } => false,
+            ChangeKind::TypeRemoved
+            | ChangeKind::TypeChanged { .. }
+            | ChangeKind::ReprChanged { .. }
+            | ChangeKind::TagChanged { .. } => true,
+            ChangeKind::NullabilityChanged { nullable } => match mode {
+                SerdeMode::Serialize => *nullable,
+                SerdeMode::Deserialize => !*nullable,
+            },
+            ChangeKind::FieldAdded { field } => field_breaking(None, Some(field), mode),
+            ChangeKind::FieldRemoved { field } => field_breaking(Some(field), None, mode),
+            ChangeKind::FieldRenamed { old_name, old, new } => match mode {
+                SerdeMode::Serialize => field_breaking(Some(old), None, mode),
+                SerdeMode::Deserialize => {
+                    !new.aliases.contains(old_name) && field_breaking(None, Some(new), mode)
+                }
+            },
+            ChangeKind::FieldModified { old, new } => {
+                old.flatten != new.flatten || field_breaking(Some(old), Some(new), mode)
+            }
+            ChangeKind::VariantAdded { variant } => variant_breaking(None, Some(variant), mode),
+            ChangeKind::VariantRemoved { variant } => variant_breaking(Some(variant), None, mode),
+            ChangeKind::VariantRenamed { old_name, old, new } => match mode {
+                SerdeMode::Serialize => variant_breaking(None, Some(new), mode),
+                SerdeMode::Deserialize => {
+                    !new.aliases.contains(old_name) && variant_breaking(Some(old), None, mode)
+                }
+            },
+            ChangeKind::VariantModified { old, new } => {
+                !same_variant_shape(&old.inner, &new.inner)
+                    || variant_breaking(Some(old), Some(new), mode)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.path)?;
+        match &self.kind {
+            ChangeKind::TypeAdded => write!(f, "type added"),
+            ChangeKind::TypeRemoved => write!(f, "type removed"),
+            ChangeKind::TypeRenamed { old, new } => {
+                write!(f, "type renamed from '{old}' to '{new}'")
+            }
+            ChangeKind::GenericsChanged { old, new } => write!(
+                f,
+                "generics changed from <{}> to <{}>",
+                join_generics(old),
+                join_generics(new)
+            ),
+            ChangeKind::TypeChanged { .. } => write!(f, "type changed"),
+            ChangeKind::NullabilityChanged { nullable: true } => write!(f, "became nullable"),
+            ChangeKind::NullabilityChanged { nullable: false } => {
+                write!(f, "is no longer nullable")
+            }
+            ChangeKind::FieldAdded { .. } => write!(f, "field added"),
+            ChangeKind::FieldRemoved { .. } => write!(f, "field removed"),
+            ChangeKind::FieldRenamed { old_name, .. } => {
+                write!(f, "field renamed from '{old_name}'")
+            }
+            ChangeKind::FieldModified { .. } => write!(f, "field modified"),
+            ChangeKind::VariantAdded { .. } => write!(f, "variant added"),
+            ChangeKind::VariantRemoved { .. } => write!(f, "variant removed"),
+            ChangeKind::VariantRenamed { old_name, .. } => {
+                write!(f, "variant renamed from '{old_name}'")
+            }
+            ChangeKind::VariantModified { .. } => write!(f, "variant modified"),
+            ChangeKind::ReprChanged { .. } => write!(f, "enum representation changed"),
+            ChangeKind::TagChanged { old, new } => write!(f, "tag changed from {old:?} to {new:?}"),
+        }
+    }
+}
+
+/// Find all the changes between two [`TypeMap`]s.
+pub fn diff(old: &TypeMap, new: &TypeMap) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let mut unmatched = new.values().flatten().collect::<Vec<_>>();
+    let ambiguous = detect_stable_id_collisions(old)
+        .into_iter()
+        .chain(detect_stable_id_collisions(new))
+        .map(|(id, _, _)| id)
+        .collect::<BTreeSet<_>>();
+
+    for old_ty in old.values().flatten() {
+        let sid = old_ty.ext().map(|ext| ext.sid);
+        let stable_id = old_ty
+            .ext()
+            .map(|ext| &ext.stable_id)
+            // Schemas saved before the `StableID` was added will have an empty one.
+            .filter(|id| !id.as_str().is_empty() && !ambiguous.contains(*id));
+        let matched = unmatched
+            .iter()
+            .position(|ty| sid.is_some() && ty.ext().map(|ext| ext.sid) == sid)
+            .or_else(|| {
+                unmatched.iter().position(|ty| {
+                    stable_id.is_some() && ty.ext().map(|ext| &ext.stable_id) == stable_id
+                })
+            })
+            .or_else(|| unmatched.iter().position(|ty| ty.name == old_ty.name));
+
+        match matched {
+            Some(i) => diff_named(old_ty, unmatched.remove(i), &mut changes),
+            None => changes.push(Change {
+                path: old_ty.name.to_string(),
+                kind: ChangeKind::TypeRemoved,
+            }),
+        }
+    }
+
+    changes.extend(unmatched.into_iter().map(|ty| Change {
+        path: ty.name.to_string(),
+        kind: ChangeKind::TypeAdded,
+    }));
+
+    changes
+}
+
+fn diff_named(old: &NamedDataType, new: &NamedDataType, changes: &mut Vec<Change>) {
+    if old.name != new.name {
+        changes.push(Change {
+            path: new.name.to_string(),
+            kind: ChangeKind::TypeRenamed {
+                old: old.name.clone(),
+                new: new.name.clone(),
+            },
+        });
+    }
+
+    let (old_generics, new_generics) = (old.inner.generics(), new.inner.generics());
+    if old_generics != new_generics {
+        changes.push(Change {
+            path: new.name.to_string(),
+            kind: ChangeKind::GenericsChanged {
+                old: old_generics.cloned().unwrap_or_default(),
+                new: new_generics.cloned().unwrap_or_default(),
+            },
+        });
+    }
+
+    diff_datatype(&new.name, &old.inner, &new.inner, changes);
+}
+
+fn diff_datatype(path: &str, old: &DataType, new: &DataType, changes: &mut Vec<Change>) {
+    match (old, new) {
+        (DataType::Nullable(old), DataType::Nullable(new)) => {
+            diff_datatype(path, old, new, changes)
+        }
+        (DataType::Nullable(old), new) => {
+            changes.push(Change {
+                path: path.to_string(),
+                kind: ChangeKind::NullabilityChanged { nullable: false },
+            });
+            diff_datatype(path, old, new, changes);
+        }
+        (old, DataType::Nullable(new)) => {
+            changes.push(Change {
+                path: path.to_string(),
+                kind: ChangeKind::NullabilityChanged { nullable: true },
+            });
+            diff_datatype(path, old, new, changes);
+        }
+        (DataType::List(old), DataType::List(new)) if old.length == new.length => {
+            diff_datatype(&format!("{path}[]"), &old.ty, &new.ty, changes)
+        }
+        (DataType::Map(old), DataType::Map(new)) => {
+            diff_datatype(&format!("{path}.<key>"), &old.0, &new.0, changes);
+            diff_datatype(&format!("{path}.<value>"), &old.1, &new.1, changes);
+        }
+        (DataType::Result(old), DataType::Result(new)) => {
+            diff_datatype(&format!("{path}.<ok>"), &old.0, &new.0, changes);
+            diff_datatype(&format!("{path}.<err>"), &old.1, &new.1, changes);
+        }
+        (DataType::Conversion(old), DataType::Conversion(new)) => {
+            diff_datatype(&format!("{path}.<into>"), &old.0, &new.0, changes);
+            diff_datatype(&format!("{path}.<from>"), &old.1, &new.1, changes);
+        }
+        (DataType::Tuple(old), DataType::Tuple(new)) if old.fields.len() == new.fields.len() => {
+            for (i, (old, new)) in old.fields.iter().zip(&new.fields).enumerate() {
+                diff_datatype(&format!("{path}.{i}"), old, new, changes);
+            }
+        }
+        (DataType::Struct(old), DataType::Struct(new)) => diff_struct(path, old, new, changes),
+        (DataType::Enum(old), DataType::Enum(new)) => diff_enum(path, old, new, changes),
+        // The referenced types are compared when we diff the type map so we only need to check it's the same type.
+        (DataType::Reference(old), DataType::Reference(new))
+            if (old.sid == new.sid || old.name == new.name)
+                && old.generics.len() == new.generics.len() =>
+        {
+            for (i, (old, new)) in old.generics.iter().zip(&new.generics).enumerate() {
+                diff_datatype(&format!("{path}<{i}>"), old, new, changes);
+            }
+        }
+        (old, new) if old == new => {}
+        (old, new) => changes.push(Change {
+            path: path.to_string(),
+            kind: ChangeKind::TypeChanged {
+                old: old.clone(),
+                new: new.clone(),
+            },
+        }),
+    }
+}
+
+fn diff_struct(path: &str, old: &StructType, new: &StructType, changes: &mut Vec<Change>) {
+    match (&old.fields, &new.fields) {
+        (StructFields::Unit, StructFields::Unit) => {}
+        (StructFields::Unnamed(old), StructFields::Unnamed(new))
+            if old.fields.len() == new.fields.len() =>
+        {
+            diff_unnamed_fields(path, old, new, changes)
+        }
+        (StructFields::Named(old), StructFields::Named(new)) => {
+            diff_named_fields(path, old, new, changes)
+        }
+        _ => changes.push(Change {
+            path: path.to_string(),
+            kind: ChangeKind::TypeChanged {
+                old: DataType::Struct(old.clone()),
+                new: DataType::Struct(new.clone()),
+            },
+        }),
+    }
+}
+
+fn diff_enum(path: &str, old: &EnumType, new: &EnumType, changes: &mut Vec<Change>) {
+    if old.repr != new.repr {
+        changes.push(Change {
+            path: path.to_string(),
+            kind: ChangeKind::ReprChanged {
+                old: old.repr.clone(),
+                new: new.repr.clone(),
+            },
+        });
+    }
+
+    let mut removed = Vec::new();
+    let mut added = new
+        .variants
+        .iter()
+        .filter(|(name, _)| !old.variants.iter().any(|(n, _)| n == name))
+        .collect::<Vec<_>>();
+
+    for (name, old_variant) in &old.variants {
+        let Some((_, new_variant)) = new.variants.iter().find(|(n, _)| n == name) else {
+            removed.push((name, old_variant));
+            continue;
+        };
+
+        let path = format!("{path}::{name}");
+        match (&old_variant.inner, &new_variant.inner) {
+            (EnumVariants::Unnamed(old), EnumVariants::Unnamed(new))
+                if old.fields.len() == new.fields.len() =>
+            {
+                diff_unnamed_fields(&path, old, new, changes)
+            }
+            (EnumVariants::Named(old), EnumVariants::Named(new)) => {
+                diff_named_fields(&path, old, new, changes)
+            }
+            (EnumVariants::Unit, EnumVariants::Unit) => {}
+            _ => {
+                changes.push(Change {
+                    path,
+                    kind: ChangeKind::VariantModified {
+                        old: old_variant.clone(),
+                        new: new_variant.clone(),
+                    },
+                });
+                continue;
+            }
+        }
+
+        if old_variant.skip_serializing != new_variant.skip_serializing
+            || old_variant.skip_deserializing != new_variant.skip_deserializing
+        {
+            changes.push(Change {
+                path,
+                kind: ChangeKind::VariantModified {
+                    old: old_variant.clone(),
+                    new: new_variant.clone(),
+                },
+            });
+        }
+    }
+
+    for (old_name, old_variant) in removed {
+        let renamed = added
+            .iter()
+            .position(|(_, v)| v.inner == old_variant.inner)
+            .map(|i| added.remove(i));
+
+        changes.push(match renamed {
+            Some((name, new_variant)) => Change {
+                path: format!("{path}::{name}"),
+                kind: ChangeKind::VariantRenamed {
+                    old_name: old_name.clone(),
+                    old: old_variant.clone(),
+                    new: new_variant.clone(),
+                },
+            },
+            None => Change {
+                path: format!("{path}::{old_name}"),
+                kind: ChangeKind::VariantRemoved {
+                    variant: old_variant.clone(),
+                },
+            },
+        });
+    }
+
+    changes.extend(added.into_iter().map(|(name, variant)| Change {
+        path: format!("{path}::{name}"),
+        kind: ChangeKind::VariantAdded {
+            variant: variant.clone(),
+        },
+    }));
+}
+
+fn diff_unnamed_fields(
+    path: &str,
+    old: &UnnamedFields,
+    new: &UnnamedFields,
+    changes: &mut Vec<Change>,
+) {
+    for (i, (old, new)) in old.fields.iter().zip(&new.fields).enumerate() {
+        diff_field(&format!("{path}.{i}"), old, new, changes);
+    }
+}
+
+fn diff_named_fields(path: &str, old: &NamedFields, new: &NamedFields, changes: &mut Vec<Change>) {
+    if old.tag != new.tag {
+        changes.push(Change {
+            path: path.to_string(),
+            kind: ChangeKind::TagChanged {
+                old: old.tag.clone(),
+                new: new.tag.clone(),
+            },
+        });
+    }
+
+    let mut removed = Vec::new();
+    let mut added = new
+        .fields
+        .iter()
+        .filter(|(name, _)| !old.fields.iter().any(|(n, _)| n == name))
+        .collect::<Vec<_>>();
+
+    for (name, old_field) in &old.fields {
+        match new.fields.iter().find(|(n, _)| n == name) {
+            Some((_, new_field)) => {
+                diff_field(&format!("{path}.{name}"), old_field, new_field, changes)
+            }
+            None => removed.push((name, old_field)),
+        }
+    }
+
+    for (old_name, old_field) in removed {
+        let renamed = added
+            .iter()
+            .position(|(_, f)| f.ty == old_field.ty)
+            .map(|i| added.remove(i));
+
+        changes.push(match renamed {
+            Some((name, new_field)) => Change {
+                path: format!("{path}.{name}"),
+                kind: ChangeKind::FieldRenamed {
+                    old_name: old_name.clone(),
+                    old: old_field.clone(),
+                    new: new_field.clone(),
+                },
+            },
+            None => Change {
+                path: format!("{path}.{old_name}"),
+                kind: ChangeKind::FieldRemoved {
+                    field: old_field.clone(),
+                },
+            },
+        });
+    }
+
+    changes.extend(added.into_iter().map(|(name, field)| Change {
+        path: format!("{path}.{name}"),
+        kind: ChangeKind::FieldAdded {
+            field: field.clone(),
+        },
+    }));
+}
+
+fn diff_field(path: &str, old: &Field, new: &Field, changes: &mut Vec<Change>) {
+    let modified = old.flatten != new.flatten
+        || [SerdeMode::Serialize, SerdeMode::Deserialize]
+            .into_iter()
+            .any(|mode| {
+                old.skip_in(mode) != new.skip_in(mode)
+                    || old.optional_in(mode) != new.optional_in(mode)
+            });
+
+    if modified {
+        changes.push(Change {
+            path: path.to_string(),
+            kind: ChangeKind::FieldModified {
+                old: old.clone(),
+                new: new.clone(),
+            },
+        });
+    }
+
+    diff_datatype(path, &old.ty, &new.ty, changes);
+}
+
+fn join_generics(generics: &[GenericType]) -> String {
+    generics
+        .iter()
+        .map(|generic| generic.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn is_required(field: &Field, mode: SerdeMode) -> bool {
+    !field.skip_in(mode) && !field.optional_in(mode)
+}
+
+// When serializing the old reader must always receive the fields it requires.
+// When deserializing the old writer must always send the fields the new type requires.
+fn field_breaking(old: Option<&Field>, new: Option<&Field>, mode: SerdeMode) -> bool {
+    let old = old.is_some_and(|f| is_required(f, mode));
+    let new = new.is_some_and(|f| is_required(f, mode));
+
+    match mode {
+        SerdeMode::Serialize => old && !new,
+        SerdeMode::Deserialize => new && !old,
+    }
+}
+
+// When serializing the old reader must know every variant we could send.
+// When deserializing we must accept every variant the old writer could send.
+fn variant_breaking(old: Option<&EnumVariant>, new: Option<&EnumVariant>, mode: SerdeMode) -> bool {
+    let old = old.is_some_and(|v| !v.skip_in(mode));
+    let new = new.is_some_and(|v| !v.skip_in(mode));
+
+    match mode {
+        SerdeMode::Serialize => new && !old,
+        SerdeMode::Deserialize => old && !new,
+    }
+}
+
+fn same_variant_shape(old: &EnumVariants, new: &EnumVariants) -> bool {
+    match (old, new) {
+        (EnumVariants::Unit, EnumVariants::Unit) => true,
+        (EnumVariants::Unnamed(old), EnumVariants::Unnamed(new)) => {
+            old.fields.len() == new.fields.len()
+        }
+        (EnumVariants::Named(_), EnumVariants::Named(_)) => true,
+        _ => false,
+    }
+}
@@ -1,7 +1,8 @@
 use std::borrow::Cow;
 
 use crate::{
-    datatype::DataType, DeprecatedType, GenericType, NamedDataType, NamedFields, UnnamedFields,
+    datatype::DataType, DeprecatedType, GenericType, Metadata, NamedDataType, NamedFields,
+    SerdeMode, UnnamedFields,
 };
 
 /// Enum type which dictates how the enum is represented.
@@ -11,11 +12,17 @@ use crate::{
 /// [`Untagged`](EnumType::Untagged) is here rather than in [`EnumRepr`] as it is the only enum representation that does not have tags on its variants.
 /// Separating it allows for better typesafety since `variants` doesn't have to be a [`Vec`] of tuples.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumType {
     pub(crate) name: Cow<'static, str>,
     pub(crate) repr: EnumRepr,
     pub(crate) generics: Vec<GenericType>,
     pub(crate) variants: Vec<(Cow<'static, str>, EnumVariant)>,
+    /// Did the user apply a `#[serde(deny_unknown_fields)]` attribute.
+    pub(crate) deny_unknown_fields: bool,
+    /// Did the user apply a `#[specta(readonly)]` attribute to the container.
+    #[cfg_attr(feature = "schema", serde(default))]
+    pub(crate) readonly: Option<bool>,
 }
 
 impl EnumType {
@@ -32,6 +39,9 @@ impl EnumType {
             name: name.into(),
             docs: Cow::Borrowed(""),
             deprecated: None,
+            #[cfg(feature = "serde_json")]
+            example: None,
+            metadata: Default::default(),
             ext: None,
             inner: DataType::Enum(self),
         }
@@ -52,6 +62,16 @@ impl EnumType {
     pub fn generics(&self) -> &Vec<GenericType> {
         &self.generics
     }
+
+    /// Will Serde reject unknown fields when deserializing the struct variants of this enum.
+    pub fn deny_unknown_fields(&self) -> bool {
+        self.deny_unknown_fields
+    }
+
+    /// Should the type be exported as immutable. `None` will fallback to the exporter's configuration.
+    pub fn readonly(&self) -> Option<bool> {
+        self.readonly
+    }
 }
 
 impl From<EnumType> for DataType {
@@ -62,6 +82,7 @@ impl From<EnumType> for DataType {
 
 /// Serde representation of an enum.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub enum EnumRepr {
     Untagged,
     External,
@@ -75,23 +96,74 @@ pub enum EnumRepr {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumVariant {
-    /// Did the user apply a `#[serde(skip)]` or `#[specta(skip)]` attribute.
+    /// Did the user apply a `#[serde(skip)]`, `#[specta(skip)]` or `#[serde(skip_serializing)]` attribute.
     ///
     /// You might think, well why not apply this in the macro and just not emit the variant?
     /// Well in Serde `A(String)` and `A(#[serde(skip)] (), String)` export as different Typescript types so the exporter needs runtime knowledge of this.
-    pub(crate) skip: bool,
+    pub(crate) skip_serializing: bool,
+    /// Did the user apply a `#[serde(skip)]`, `#[specta(skip)]` or `#[serde(skip_deserializing)]` attribute.
+    pub(crate) skip_deserializing: bool,
+    /// Did the user apply a `#[serde(other)]` attribute.
+    ///
+    /// The variant will be deserialized from any tag which doesn't match another variant.
+    pub(crate) other: bool,
+    /// Alternative names the variant will be accepted under when deserializing. Set using `#[serde(alias = "...")]`.
+    pub(crate) aliases: Vec<Cow<'static, str>>,
     /// Documentation comments for the field.
     pub(crate) docs: Cow<'static, str>,
     /// Deprecated attribute for the field.
     pub(crate) deprecated: Option<DeprecatedType>,
+    /// User defined metadata from `#[specta(meta(key = "value"))]`.
+    pub(crate) metadata: Metadata,
     /// The type of the variant.
     pub(crate) inner: EnumVariants,
 }
 
 impl EnumVariant {
+    pub(crate) fn new(inner: EnumVariants) -> Self {
+        Self {
+            skip_serializing: false,
+            skip_deserializing: false,
+            other: false,
+            aliases: vec![],
+            docs: Cow::Borrowed(""),
+            deprecated: None,
+            metadata: Default::default(),
+            inner,
+        }
+    }
+
+    /// Is the variant skipped in either direction.
+    ///
+    /// Use [`EnumVariant::skip_in`] if you are only exporting one side of a Serde round trip.
     pub fn skip(&self) -> bool {
-        self.skip
+        self.skip_serializing || self.skip_deserializing
+    }
+
+    /// Is the variant skipped when data is flowing in the given direction.
+    pub fn skip_in(&self, mode: SerdeMode) -> bool {
+        match mode {
+            SerdeMode::Serialize => self.skip_serializing,
+            SerdeMode::Deserialize => self.skip_deserializing,
+        }
+    }
+
+    pub fn skip_serializing(&self) -> bool {
+        self.skip_serializing
+    }
+
+    pub fn skip_deserializing(&self) -> bool {
+        self.skip_deserializing
+    }
+
+    pub fn other(&self) -> bool {
+        self.other
+    }
+
+    pub fn aliases(&self) -> &Vec<Cow<'static, str>> {
+        &self.aliases
     }
 
     pub fn docs(&self) -> &Cow<'static, str> {
@@ -102,6 +174,10 @@ impl EnumVariant {
         self.deprecated.as_ref()
     }
 
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
     pub fn inner(&self) -> &EnumVariants {
         &self.inner
     }
@@ -109,6 +185,7 @@ impl EnumVariant {
 
 /// Type of an [`EnumType`] variant.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub enum EnumVariants {
     /// A unit enum variant
     /// Eg. `Variant`
@@ -2,43 +2,160 @@
 
 use std::borrow::Cow;
 
-use crate::{DataType, DeprecatedType};
+use crate::{DataType, DeprecatedType, FieldConstraints, Metadata, SerdeMode};
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub struct Field {
-    /// Did the user apply a `#[serde(skip)]` or `#[specta(skip)]` attribute.
+    /// Did the user apply a `#[serde(skip)]`, `#[specta(skip)]` or `#[serde(skip_serializing)]` attribute.
     ///
     /// You might think, well why not apply this in the macro and just not emit the variant?
     /// Well in Serde `A(String)` and `A(#[serde(skip)] (), String)` export as different Typescript types so the exporter needs runtime knowledge of this.
-    pub(crate) skip: bool,
+    pub(crate) skip_serializing: bool,
+    /// Did the user apply a `#[serde(skip)]`, `#[specta(skip)]` or `#[serde(skip_deserializing)]` attribute.
+    pub(crate) skip_deserializing: bool,
     /// Did the user apply a `#[specta(optional)]` attribute.
     pub(crate) optional: bool,
+    /// Did the user apply a `#[serde(default)]` attribute. The field may be missing when deserializing.
+    pub(crate) default: bool,
+    /// The value of the field when it's missing while deserializing. Only captured for `#[serde(default)]` fields which implement `Serialize`.
+    #[cfg(feature = "serde_json")]
+    pub(crate) default_value: Option<serde_json::Value>,
+    /// Did the user apply a `#[serde(skip_serializing_if = "...")]` attribute. The field may be missing when serializing.
+    pub(crate) skip_serializing_if: bool,
     /// Did the user apply a `#[serde(flatten)]` or `#[specta(flatten)]` attribute.
     pub(crate) flatten: bool,
+    /// Alternative names the field will be accepted under when deserializing. Set using `#[serde(alias = "...")]`.
+    pub(crate) aliases: Vec<Cow<'static, str>>,
+    /// Did the user apply a `#[specta(readonly)]` attribute to the field or its container.
+    ///
+    /// `None` means the exporter's configuration decides.
+    pub(crate) readonly: Option<bool>,
+    /// Validation rules for the value of the field.
+    pub(crate) constraints: FieldConstraints,
+    /// An example value of the field from `#[specta(example = ...)]`.
+    #[cfg(feature = "serde_json")]
+    pub(crate) example: Option<serde_json::Value>,
     /// Deprecated attribute for the field.
     pub(crate) deprecated: Option<DeprecatedType>,
+    /// User defined metadata from `#[specta(meta(key = "value"))]`.
+    pub(crate) metadata: Metadata,
     /// Documentation comments for the field.
     pub(crate) docs: Cow<'static, str>,
     pub(crate) ty: DataType,
 }
 
 impl Field {
+    /// Create a field of the given type. Use [`Field::builder`] to configure it.
+    pub fn new(ty: DataType) -> Self {
+        Self {
+            skip_serializing: false,
+            skip_deserializing: false,
+            optional: false,
+            default: false,
+            #[cfg(feature = "serde_json")]
+            default_value: None,
+            skip_serializing_if: false,
+            flatten: false,
+            aliases: vec![],
+            readonly: None,
+            constraints: Default::default(),
+            #[cfg(feature = "serde_json")]
+            example: None,
+            deprecated: None,
+            metadata: Default::default(),
+            docs: Cow::Borrowed(""),
+            ty,
+        }
+    }
+
+    /// Is the field skipped in either direction.
+    ///
+    /// Use [`Field::skip_in`] if you are only exporting one side of a Serde round trip.
     pub fn skip(&self) -> bool {
-        self.skip
+        self.skip_serializing || self.skip_deserializing
+    }
+
+    /// Is the field skipped when data is flowing in the given direction.
+    pub fn skip_in(&self, mode: SerdeMode) -> bool {
+        match mode {
+            SerdeMode::Serialize => self.skip_serializing,
+            SerdeMode::Deserialize => self.skip_deserializing,
+        }
+    }
+
+    pub fn skip_serializing(&self) -> bool {
+        self.skip_serializing
     }
 
+    pub fn skip_deserializing(&self) -> bool {
+        self.skip_deserializing
+    }
+
+    /// Is the field optional in either direction.
+    ///
+    /// Use [`Field::optional_in`] if you are only exporting one side of a Serde round trip.
     pub fn optional(&self) -> bool {
+        self.optional || self.default || self.skip_serializing_if
+    }
+
+    /// Can the field be missing when data is flowing in the given direction.
+    ///
+    /// Serde will also accept a missing `Option<T>` field when deserializing so nullable fields are always optional for [`SerdeMode::Deserialize`].
+    pub fn optional_in(&self, mode: SerdeMode) -> bool {
         self.optional
+            || match mode {
+                SerdeMode::Serialize => self.skip_serializing_if,
+                SerdeMode::Deserialize => self.default || matches!(self.ty, DataType::Nullable(_)),
+            }
+    }
+
+    pub fn default(&self) -> bool {
+        self.default
+    }
+
+    /// The serialized default value of the field.
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn default_value(&self) -> Option<&serde_json::Value> {
+        self.default_value.as_ref()
+    }
+
+    /// An example value of the field from `#[specta(example = ...)]`.
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn example(&self) -> Option<&serde_json::Value> {
+        self.example.as_ref()
+    }
+
+    pub fn skip_serializing_if(&self) -> bool {
+        self.skip_serializing_if
     }
 
     pub fn flatten(&self) -> bool {
         self.flatten
     }
 
+    pub fn aliases(&self) -> &Vec<Cow<'static, str>> {
+        &self.aliases
+    }
+
+    pub fn constraints(&self) -> &FieldConstraints {
+        &self.constraints
+    }
+
+    pub fn readonly(&self) -> Option<bool> {
+        self.readonly
+    }
+
     pub fn deprecated(&self) -> Option<&DeprecatedType> {
         self.deprecated.as_ref()
     }
 
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
     pub fn docs(&self) -> &Cow<'static, str> {
         &self.docs
     }
@@ -49,6 +166,7 @@ impl Field {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnnamedFields {
     pub(crate) fields: Vec<Field>,
 }
@@ -74,6 +192,7 @@ impl UnnamedFields {
 /// }
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub struct NamedFields {
     pub(crate) fields: Vec<(Cow<'static, str>, Field)>,
     pub(crate) tag: Option<Cow<'static, str>>,
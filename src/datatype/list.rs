@@ -0,0 +1,44 @@
+use crate::DataType;
+
+/// A list of items. Eg. a `Vec`, `HashSet` or `[T; N]`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
+pub struct ListType {
+    pub(crate) ty: Box<DataType>,
+    /// The number of items in the list if it's fixed. Eg. `N` for `[T; N]`.
+    pub(crate) length: Option<usize>,
+}
+
+impl ListType {
+    /// A list of items with a variable length. Eg. a `Vec<T>`.
+    pub fn new(ty: DataType) -> Self {
+        Self {
+            ty: Box::new(ty),
+            length: None,
+        }
+    }
+
+    /// A list with a fixed number of items. Eg. a `[T; N]`.
+    pub fn fixed(ty: DataType, length: usize) -> Self {
+        Self {
+            ty: Box::new(ty),
+            length: Some(length),
+        }
+    }
+
+    /// The type of the items in the list.
+    pub fn ty(&self) -> &DataType {
+        &self.ty
+    }
+
+    /// The number of items in the list if it's fixed. Eg. `N` for `[T; N]`.
+    pub fn length(&self) -> Option<usize> {
+        self.length
+    }
+}
+
+impl From<ListType> for DataType {
+    fn from(t: ListType) -> Self {
+        Self::List(t)
+    }
+}
@@ -7,6 +7,7 @@ use crate::DataType;
 /// You'll probably never use this type directly,
 /// it's more for library authors.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 #[non_exhaustive]
 pub enum LiteralType {
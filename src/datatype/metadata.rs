@@ -0,0 +1,45 @@
+use std::borrow::Cow;
+
+/// Arbitrary key/value pairs attached to a type, field or enum variant.
+///
+/// These are set using `#[specta(meta(key = "value"))]`. Specta doesn't use them itself, they exist for custom exporters and comment formatters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
+pub struct Metadata(pub(crate) Vec<(Cow<'static, str>, Cow<'static, str>)>);
+
+impl Metadata {
+    /// Get the value for a key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_ref())
+    }
+
+    /// Set the value for a key, replacing any existing value.
+    pub fn insert(
+        &mut self,
+        key: impl Into<Cow<'static, str>>,
+        value: impl Into<Cow<'static, str>>,
+    ) {
+        let key = key.into();
+        let value = value.into();
+        match self.0.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = value,
+            None => self.0.push((key, value)),
+        }
+    }
+
+    /// Iterate over the key/value pairs in the order they were defined.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_ref(), v.as_ref()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
@@ -4,20 +4,34 @@ use std::{
     fmt::Display,
 };
 
+mod builder;
+mod constraints;
+pub mod diff;
 mod r#enum;
 mod fields;
+mod list;
 mod literal;
+mod metadata;
 mod named;
 mod primitive;
+#[cfg(feature = "schema")]
+mod schema;
 mod r#struct;
 mod tuple;
+pub mod visit;
 
+pub use builder::*;
+pub use constraints::*;
 pub use fields::*;
+pub use list::*;
 pub use literal::*;
+pub use metadata::*;
 pub use named::*;
 pub use primitive::*;
 pub use r#enum::*;
 pub use r#struct::*;
+#[cfg(feature = "schema")]
+pub use schema::*;
 pub use tuple::*;
 
 use crate::SpectaID;
@@ -40,13 +54,14 @@ pub struct DefOpts<'a> {
 ///
 /// A language exporter takes this general format and converts it into a language specific syntax.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataType {
     // Always inlined
     Any,
     Primitive(PrimitiveType),
     Literal(LiteralType),
-    /// Either a `Set` or a `Vec`
-    List(Box<DataType>),
+    /// Either a `Set`, a `Vec` or a fixed-length array
+    List(ListType),
     Nullable(Box<DataType>),
     Map(Box<(DataType, DataType)>),
     // Anonymous Reference types
@@ -55,6 +70,10 @@ pub enum DataType {
     Tuple(TupleType),
     // Result
     Result(Box<(DataType, DataType)>),
+    /// A type which Serde serializes as the first type and deserializes from the second.
+    ///
+    /// This comes from a container with `#[serde(into = "...")]` and/or `#[serde(from = "...")]` (or `try_from`). When only one is set the other direction uses the container's own shape.
+    Conversion(Box<(DataType, DataType)>),
     // A reference type that has already been defined
     Reference(DataTypeReference),
     Generic(GenericType),
@@ -77,6 +96,9 @@ impl DataType {
             name: name.into(),
             docs: Cow::Borrowed(""),
             deprecated: None,
+            #[cfg(feature = "serde_json")]
+            example: None,
+            metadata: Default::default(),
             ext: None,
             inner: self,
         }
@@ -84,6 +106,7 @@ impl DataType {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum DeprecatedType {
     /// A type that has been deprecated without a message.
@@ -109,6 +132,7 @@ pub enum DeprecatedType {
 // This doesn't account for flattening and inlining recursive types, however, which will
 // require a more complex solution since it will require multiple processing stages.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataTypeReference {
     pub(crate) name: Cow<'static, str>,
     pub(crate) sid: SpectaID,
@@ -116,6 +140,15 @@ pub struct DataTypeReference {
 }
 
 impl DataTypeReference {
+    /// Create a reference to a named type. The `sid` should match the [`NamedDataTypeExt::sid`] of the type in the [`TypeMap`].
+    pub fn new(name: impl Into<Cow<'static, str>>, sid: SpectaID, generics: Vec<DataType>) -> Self {
+        Self {
+            name: name.into(),
+            sid,
+            generics,
+        }
+    }
+
     pub fn name(&self) -> &Cow<'static, str> {
         &self.name
     }
@@ -135,6 +168,7 @@ impl DataTypeReference {
 ///
 /// A `GenericType` holds the identifier of the generic. Eg. Given a generic type `struct A<T>(T);` the generics will be represented as `vec![GenericType("A".into())]`
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub struct GenericType(pub(crate) Cow<'static, str>);
 
 impl Display for GenericType {
@@ -166,6 +200,8 @@ impl<T: Into<DataType> + 'static> From<Vec<T>> for DataType {
         DataType::Enum(EnumType {
             name: "Vec".into(),
             repr: EnumRepr::Untagged,
+            deny_unknown_fields: false,
+            readonly: None,
             variants: t
                 .into_iter()
                 .map(|t| {
@@ -177,21 +213,9 @@ impl<T: Into<DataType> + 'static> From<Vec<T>> for DataType {
                             // TODO: This is probs gonna cause problems so we should try and remove the need for this entire impl block if we can.
                             _ => "".into(),
                         },
-                        EnumVariant {
-                            skip: false,
-                            docs: Cow::Borrowed(""),
-                            deprecated: None,
-                            inner: EnumVariants::Unnamed(UnnamedFields {
-                                fields: vec![Field {
-                                    skip: false,
-                                    optional: false,
-                                    flatten: false,
-                                    deprecated: None,
-                                    docs: Cow::Borrowed(""),
-                                    ty,
-                                }],
-                            }),
-                        },
+                        EnumVariant::new(EnumVariants::Unnamed(UnnamedFields {
+                            fields: vec![Field::new(ty)],
+                        })),
                     )
                 })
                 .collect(),
@@ -1,14 +1,22 @@
 use std::borrow::Cow;
 
-use crate::{DataType, DeprecatedType, ImplLocation, SpectaID};
+use crate::{DataType, DeprecatedType, ImplLocation, Metadata, SpectaID, StableID};
 
 /// A NamedDataTypeImpl includes extra information which is only available for [NamedDataType]'s that come from a real Rust type.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub struct NamedDataTypeExt {
     /// The Specta ID for the type. The value for this should come from the `sid!();` macro.
     pub(crate) sid: SpectaID,
     /// The code location where this type is implemented. Used for error reporting.
     pub(crate) impl_location: ImplLocation,
+    /// An identifier for the type which doesn't change between builds.
+    #[cfg_attr(feature = "schema", serde(default))]
+    pub(crate) stable_id: StableID,
+    /// The Rust module path the type is defined in. Eg. `my_crate::api::users`.
+    // Added after the first version of the schema so it must be optional to load old ones.
+    #[cfg_attr(feature = "schema", serde(default))]
+    pub(crate) module_path: Cow<'static, str>,
     // TODO: Undeprecate this and handle it properly!
     // TODO: Support different export contexts
     /// DEPRECATED. This is not used and shouldn't be. Will be removed in Specta v2!
@@ -24,6 +32,14 @@ impl NamedDataTypeExt {
         &self.impl_location
     }
 
+    pub fn stable_id(&self) -> &StableID {
+        &self.stable_id
+    }
+
+    pub fn module_path(&self) -> &Cow<'static, str> {
+        &self.module_path
+    }
+
     pub fn export(&self) -> Option<bool> {
         self.export
     }
@@ -31,6 +47,7 @@ impl NamedDataTypeExt {
 
 /// A named type represents a non-primitive type capable of being exported as it's own named entity.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub struct NamedDataType {
     /// The name of the type
     pub(crate) name: Cow<'static, str>,
@@ -38,6 +55,11 @@ pub struct NamedDataType {
     pub(crate) docs: Cow<'static, str>,
     /// The Rust deprecated comment if the type is deprecated.
     pub(crate) deprecated: Option<DeprecatedType>,
+    /// An example value of the type from `#[specta(example = ...)]`.
+    #[cfg(feature = "serde_json")]
+    pub(crate) example: Option<serde_json::Value>,
+    /// User defined metadata from `#[specta(meta(key = "value"))]`.
+    pub(crate) metadata: Metadata,
     /// Extra information that comes from a real Rust type (using the `Type` macro).
     /// This will be `None` when constructing [NamedDataType] using `StructType::to_named` or `TupleType::to_named` since those types do not correspond to actual Rust types.
     pub(crate) ext: Option<NamedDataTypeExt>,
@@ -59,6 +81,17 @@ impl NamedDataType {
         self.deprecated.as_ref()
     }
 
+    /// An example value of the type from `#[specta(example = ...)]`.
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn example(&self) -> Option<&serde_json::Value> {
+        self.example.as_ref()
+    }
+
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
     pub fn ext(&self) -> Option<&NamedDataTypeExt> {
         self.ext.as_ref()
     }
@@ -1,8 +1,11 @@
+use std::borrow::Cow;
+
 use crate::DataType;
 
 /// Type of primitives like numbers and strings.
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub enum PrimitiveType {
     i8,
     i16,
@@ -21,6 +24,10 @@ pub enum PrimitiveType {
     bool,
     char,
     String,
+    /// A string which holds a value with a well-known format. Eg. a UUID or a date.
+    ///
+    /// Exporters which don't understand the format should treat this the same as [`PrimitiveType::String`].
+    FormattedString(StringFormat),
 }
 
 impl PrimitiveType {
@@ -43,9 +50,95 @@ impl PrimitiveType {
             Self::f64 => "f64",
             Self::bool => "bool",
             Self::char => "char",
-            Self::String => "String",
+            Self::String | Self::FormattedString(_) => "String",
+        }
+    }
+}
+
+/// The semantic format of a [`PrimitiveType::FormattedString`].
+///
+/// The names returned by [`StringFormat::as_str`] follow the [JSON Schema formats](https://json-schema.org/understanding-json-schema/reference/string#built-in-formats) where one exists.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum StringFormat {
+    /// A UUID. Eg. `67e55044-10b1-426f-9247-bb680e5fe0c8`
+    Uuid,
+    /// A RFC 3339 date and time with a timezone offset. Eg. `2018-11-13T20:20:39+00:00`
+    DateTime,
+    /// A date and time without a timezone. Eg. `2018-11-13T20:20:39`
+    NaiveDateTime,
+    /// A RFC 3339 full-date. Eg. `2018-11-13`
+    Date,
+    /// A RFC 3339 time. Eg. `20:20:39`
+    Time,
+    /// A ISO 8601 duration. Eg. `P3D`
+    Duration,
+    /// A URI. Eg. `https://example.com`
+    Uri,
+    /// An arbitrary precision decimal number. Eg. `3.14159`
+    Decimal,
+    /// An IPv4 address. Eg. `127.0.0.1`
+    Ipv4,
+    /// An IPv6 address. Eg. `::1`
+    Ipv6,
+    /// An IPv4 or IPv6 address.
+    Ip,
+    /// An IP network in CIDR notation. Eg. `192.168.0.0/16`
+    IpNetwork,
+    /// A MAC address. Eg. `00:00:5e:00:53:af`
+    MacAddress,
+    /// A format which isn't known to Specta.
+    Custom(Cow<'static, str>),
+}
+
+impl StringFormat {
+    /// Construct a [`StringFormat`] from its name, falling back to [`StringFormat::Custom`] for unknown formats.
+    pub fn new(format: impl Into<Cow<'static, str>>) -> Self {
+        let format = format.into();
+        match &*format {
+            "uuid" => Self::Uuid,
+            "date-time" => Self::DateTime,
+            "naive-date-time" => Self::NaiveDateTime,
+            "date" => Self::Date,
+            "time" => Self::Time,
+            "duration" => Self::Duration,
+            "uri" => Self::Uri,
+            "decimal" => Self::Decimal,
+            "ipv4" => Self::Ipv4,
+            "ipv6" => Self::Ipv6,
+            "ip" => Self::Ip,
+            "ip-network" => Self::IpNetwork,
+            "mac-address" => Self::MacAddress,
+            _ => Self::Custom(format),
         }
     }
+
+    /// The name of the format.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Uuid => "uuid",
+            Self::DateTime => "date-time",
+            Self::NaiveDateTime => "naive-date-time",
+            Self::Date => "date",
+            Self::Time => "time",
+            Self::Duration => "duration",
+            Self::Uri => "uri",
+            Self::Decimal => "decimal",
+            Self::Ipv4 => "ipv4",
+            Self::Ipv6 => "ipv6",
+            Self::Ip => "ip",
+            Self::IpNetwork => "ip-network",
+            Self::MacAddress => "mac-address",
+            Self::Custom(format) => format,
+        }
+    }
+}
+
+impl From<StringFormat> for DataType {
+    fn from(t: StringFormat) -> Self {
+        Self::Primitive(PrimitiveType::FormattedString(t))
+    }
 }
 
 impl From<PrimitiveType> for DataType {
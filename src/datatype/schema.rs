@@ -0,0 +1,101 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{NamedDataType, SpectaID, TypeMap};
+
+/// A serializable snapshot of a [`TypeMap`].
+///
+/// This is a versioned intermediate representation of the schema which can be written to disk (Eg. as JSON) and loaded back later.
+/// It's useful for caching the schema between builds or passing it to tools outside of Rust.
+///
+/// ```rust
+/// use specta::{datatype::Schema, NamedType, Type, TypeMap};
+///
+/// #[derive(Type)]
+/// pub struct MyType {
+///     pub a: String,
+/// }
+///
+/// let mut type_map = TypeMap::default();
+/// let ty = MyType::definition_named_data_type(specta::DefOpts {
+///     parent_inline: false,
+///     type_map: &mut type_map,
+/// });
+/// type_map.insert(MyType::SID, Some(ty));
+///
+/// let schema = Schema::new(&type_map);
+/// assert_eq!(schema.version, Schema::VERSION);
+/// assert_eq!(schema.into_type_map(), type_map);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(docsrs, doc(cfg(feature = "schema")))]
+pub struct Schema {
+    /// The version of the format this schema was serialized with.
+    /// Deserializing a schema with a newer version than [`Schema::VERSION`] will fail.
+    #[serde(deserialize_with = "deserialize_version")]
+    pub version: u32,
+    /// Every entry of the [`TypeMap`], ordered by [`SpectaID`].
+    pub types: Vec<SchemaEntry>,
+}
+
+/// A single entry in a [`Schema`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(docsrs, doc(cfg(feature = "schema")))]
+pub struct SchemaEntry {
+    /// The key of the type in the [`TypeMap`].
+    pub sid: SpectaID,
+    /// [`None`] if the type is a placeholder in the [`TypeMap`].
+    #[serde(rename = "type")]
+    pub ty: Option<NamedDataType>,
+}
+
+impl Schema {
+    /// The version of the format produced by this version of Specta.
+    /// This is bumped whenever the serialized representation of [`DataType`](crate::DataType) changes in an incompatible way.
+    pub const VERSION: u32 = 1;
+
+    /// Create a schema with the current [`Schema::VERSION`] from a [`TypeMap`].
+    pub fn new(type_map: &TypeMap) -> Self {
+        Self {
+            version: Self::VERSION,
+            types: type_map
+                .iter()
+                .map(|(sid, ty)| SchemaEntry {
+                    sid: *sid,
+                    ty: ty.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Convert the schema back into a [`TypeMap`]. Loading a schema and converting it back gives the same [`TypeMap`] it was created from.
+    pub fn into_type_map(self) -> TypeMap {
+        self.types
+            .into_iter()
+            .map(|entry| (entry.sid, entry.ty))
+            .collect()
+    }
+}
+
+impl From<&TypeMap> for Schema {
+    fn from(type_map: &TypeMap) -> Self {
+        Self::new(type_map)
+    }
+}
+
+impl From<Schema> for TypeMap {
+    fn from(schema: Schema) -> Self {
+        schema.into_type_map()
+    }
+}
+
+fn deserialize_version<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    let version = u32::deserialize(deserializer)?;
+    if version > Schema::VERSION {
+        return Err(serde::de::Error::custom(format!(
+            "schema version {version} is newer than the supported version {}",
+            Schema::VERSION
+        )));
+    }
+
+    Ok(version)
+}
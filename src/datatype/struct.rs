@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use crate::{DataType, GenericType, NamedDataType, NamedFields, UnnamedFields};
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub enum StructFields {
     /// A unit struct.
     ///
@@ -19,10 +20,16 @@ pub enum StructFields {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructType {
     pub(crate) name: Cow<'static, str>,
     pub(crate) generics: Vec<GenericType>,
     pub(crate) fields: StructFields,
+    /// Did the user apply a `#[serde(deny_unknown_fields)]` attribute.
+    pub(crate) deny_unknown_fields: bool,
+    /// Did the user apply a `#[specta(readonly)]` attribute to the container.
+    #[cfg_attr(feature = "schema", serde(default))]
+    pub(crate) readonly: Option<bool>,
 }
 
 impl StructType {
@@ -39,6 +46,9 @@ impl StructType {
             name: name.into(),
             docs: Cow::Borrowed(""),
             deprecated: None,
+            #[cfg(feature = "serde_json")]
+            example: None,
+            metadata: Default::default(),
             ext: None,
             inner: DataType::Struct(self),
         }
@@ -56,6 +66,16 @@ impl StructType {
         &self.fields
     }
 
+    /// Will Serde reject unknown fields when deserializing this struct.
+    pub fn deny_unknown_fields(&self) -> bool {
+        self.deny_unknown_fields
+    }
+
+    /// Should the type be exported as immutable. `None` will fallback to the exporter's configuration.
+    pub fn readonly(&self) -> Option<bool> {
+        self.readonly
+    }
+
     pub fn tag(&self) -> Option<&Cow<'static, str>> {
         match &self.fields {
             StructFields::Unit => None,
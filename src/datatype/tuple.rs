@@ -7,11 +7,16 @@ use crate::{DataType, NamedDataType};
 /// Represented in Rust as `(...)` and in TypeScript as `[...]`.
 /// Be aware `()` is treated specially as `null` in Typescript.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(serde::Serialize, serde::Deserialize))]
 pub struct TupleType {
     pub(crate) fields: Vec<DataType>,
 }
 
 impl TupleType {
+    pub fn new(fields: Vec<DataType>) -> Self {
+        Self { fields }
+    }
+
     /// convert a [`TupleType`] to an anonymous [`DataType`].
     pub fn to_anonymous(self) -> DataType {
         DataType::Tuple(self)
@@ -25,6 +30,9 @@ impl TupleType {
             name: name.into(),
             docs: Cow::Borrowed(""),
             deprecated: None,
+            #[cfg(feature = "serde_json")]
+            example: None,
+            metadata: Default::default(),
             ext: None,
             inner: DataType::Tuple(self),
         }
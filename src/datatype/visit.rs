@@ -0,0 +1,296 @@
+//! Traversal of [`DataType`]s.
+//!
+//! Implement [`Visitor`] (or [`VisitorMut`] to modify the types) and override the methods for the nodes you care about.
+//! The default implementation of each method calls the matching `walk_*` function which visits the node's children, so call it from your override if you want to keep descending.
+//!
+//! ```rust
+//! use specta::{datatype::visit::{walk_field, Visitor}, Field};
+//!
+//! #[derive(Default)]
+//! struct OptionalFields(Vec<String>);
+//!
+//! impl Visitor for OptionalFields {
+//!     fn visit_field(&mut self, name: Option<&str>, field: &Field) {
+//!         if let (Some(name), true) = (name, field.optional()) {
+//!             self.0.push(name.to_string());
+//!         }
+//!         walk_field(self, field);
+//!     }
+//! }
+//! ```
+
+use std::collections::{BTreeSet, VecDeque};
+
+use crate::{
+    DataType, DataTypeReference, EnumType, EnumVariant, EnumVariants, Field, NamedDataType,
+    NamedFields, SpectaID, StructFields, StructType, TypeMap, UnnamedFields,
+};
+
+/// Visit a [`DataType`] by reference.
+pub trait Visitor {
+    fn visit_named_datatype(&mut self, ty: &NamedDataType) {
+        walk_named_datatype(self, ty)
+    }
+
+    fn visit_datatype(&mut self, ty: &DataType) {
+        walk_datatype(self, ty)
+    }
+
+    fn visit_struct(&mut self, ty: &StructType) {
+        walk_struct(self, ty)
+    }
+
+    fn visit_enum(&mut self, ty: &EnumType) {
+        walk_enum(self, ty)
+    }
+
+    fn visit_variant(&mut self, _name: &str, variant: &EnumVariant) {
+        walk_variant(self, variant)
+    }
+
+    /// Visit a field of a struct or enum variant. `name` is `None` for unnamed fields.
+    fn visit_field(&mut self, _name: Option<&str>, field: &Field) {
+        walk_field(self, field)
+    }
+
+    /// Visit a reference to a named type. This doesn't follow the reference, use [`visit_with_references`] for that.
+    fn visit_reference(&mut self, reference: &DataTypeReference) {
+        walk_reference(self, reference)
+    }
+}
+
+/// Visit the children of the named datatype.
+pub fn walk_named_datatype<V: Visitor + ?Sized>(visitor: &mut V, ty: &NamedDataType) {
+    visitor.visit_datatype(&ty.inner);
+}
+
+/// Visit the children of the datatype.
+pub fn walk_datatype<V: Visitor + ?Sized>(visitor: &mut V, ty: &DataType) {
+    match ty {
+        DataType::Any | DataType::Primitive(_) | DataType::Literal(_) | DataType::Generic(_) => {}
+        DataType::List(list) => visitor.visit_datatype(&list.ty),
+        DataType::Nullable(ty) => visitor.visit_datatype(ty),
+        DataType::Map(ty) | DataType::Result(ty) | DataType::Conversion(ty) => {
+            visitor.visit_datatype(&ty.0);
+            visitor.visit_datatype(&ty.1);
+        }
+        DataType::Struct(ty) => visitor.visit_struct(ty),
+        DataType::Enum(ty) => visitor.visit_enum(ty),
+        DataType::Tuple(ty) => {
+            for ty in &ty.fields {
+                visitor.visit_datatype(ty);
+            }
+        }
+        DataType::Reference(reference) => visitor.visit_reference(reference),
+    }
+}
+
+/// Visit the children of the struct.
+pub fn walk_struct<V: Visitor + ?Sized>(visitor: &mut V, ty: &StructType) {
+    match &ty.fields {
+        StructFields::Unit => {}
+        StructFields::Unnamed(fields) => walk_unnamed_fields(visitor, fields),
+        StructFields::Named(fields) => walk_named_fields(visitor, fields),
+    }
+}
+
+/// Visit the children of the enum.
+pub fn walk_enum<V: Visitor + ?Sized>(visitor: &mut V, ty: &EnumType) {
+    for (name, variant) in &ty.variants {
+        visitor.visit_variant(name, variant);
+    }
+}
+
+/// Visit the children of the enum variant.
+pub fn walk_variant<V: Visitor + ?Sized>(visitor: &mut V, variant: &EnumVariant) {
+    match &variant.inner {
+        EnumVariants::Unit => {}
+        EnumVariants::Unnamed(fields) => walk_unnamed_fields(visitor, fields),
+        EnumVariants::Named(fields) => walk_named_fields(visitor, fields),
+    }
+}
+
+/// Visit the children of the field.
+pub fn walk_field<V: Visitor + ?Sized>(visitor: &mut V, field: &Field) {
+    visitor.visit_datatype(&field.ty);
+}
+
+/// Visit the children of the reference.
+pub fn walk_reference<V: Visitor + ?Sized>(visitor: &mut V, reference: &DataTypeReference) {
+    for ty in &reference.generics {
+        visitor.visit_datatype(ty);
+    }
+}
+
+fn walk_unnamed_fields<V: Visitor + ?Sized>(visitor: &mut V, fields: &UnnamedFields) {
+    for field in &fields.fields {
+        visitor.visit_field(None, field);
+    }
+}
+
+fn walk_named_fields<V: Visitor + ?Sized>(visitor: &mut V, fields: &NamedFields) {
+    for (name, field) in &fields.fields {
+        visitor.visit_field(Some(name), field);
+    }
+}
+
+/// Visit a [`DataType`] and then every named type it references (directly or through other named types) which is in the `type_map`.
+///
+/// Each named type is visited at most once so recursive types terminate. References to types missing from the `type_map` are skipped.
+pub fn visit_with_references<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    ty: &DataType,
+    type_map: &TypeMap,
+) {
+    let mut queue = VecDeque::new();
+    let mut visited = BTreeSet::new();
+
+    visitor.visit_datatype(ty);
+    References(&mut queue).visit_datatype(ty);
+
+    while let Some(sid) = queue.pop_front() {
+        if !visited.insert(sid) {
+            continue;
+        }
+
+        if let Some(Some(ty)) = type_map.get(&sid) {
+            visitor.visit_named_datatype(ty);
+            References(&mut queue).visit_named_datatype(ty);
+        }
+    }
+}
+
+/// Visit every named type in the `type_map`.
+pub fn visit_type_map<V: Visitor + ?Sized>(visitor: &mut V, type_map: &TypeMap) {
+    for ty in type_map.values().flatten() {
+        visitor.visit_named_datatype(ty);
+    }
+}
+
+/// Collects the [`SpectaID`] of every reference in a type.
+struct References<'a>(&'a mut VecDeque<SpectaID>);
+
+impl Visitor for References<'_> {
+    fn visit_reference(&mut self, reference: &DataTypeReference) {
+        self.0.push_back(reference.sid);
+        walk_reference(self, reference);
+    }
+}
+
+/// Visit a [`DataType`] by mutable reference.
+pub trait VisitorMut {
+    fn visit_named_datatype_mut(&mut self, ty: &mut NamedDataType) {
+        walk_named_datatype_mut(self, ty)
+    }
+
+    fn visit_datatype_mut(&mut self, ty: &mut DataType) {
+        walk_datatype_mut(self, ty)
+    }
+
+    fn visit_struct_mut(&mut self, ty: &mut StructType) {
+        walk_struct_mut(self, ty)
+    }
+
+    fn visit_enum_mut(&mut self, ty: &mut EnumType) {
+        walk_enum_mut(self, ty)
+    }
+
+    fn visit_variant_mut(&mut self, _name: &str, variant: &mut EnumVariant) {
+        walk_variant_mut(self, variant)
+    }
+
+    /// Visit a field of a struct or enum variant. `name` is `None` for unnamed fields.
+    fn visit_field_mut(&mut self, _name: Option<&str>, field: &mut Field) {
+        walk_field_mut(self, field)
+    }
+
+    /// Visit a reference to a named type. This doesn't follow the reference, use [`visit_type_map_mut`] to modify the named types.
+    fn visit_reference_mut(&mut self, reference: &mut DataTypeReference) {
+        walk_reference_mut(self, reference)
+    }
+}
+
+/// Visit the children of the named datatype, mutably.
+pub fn walk_named_datatype_mut<V: VisitorMut + ?Sized>(visitor: &mut V, ty: &mut NamedDataType) {
+    visitor.visit_datatype_mut(&mut ty.inner);
+}
+
+/// Visit the children of the datatype, mutably.
+pub fn walk_datatype_mut<V: VisitorMut + ?Sized>(visitor: &mut V, ty: &mut DataType) {
+    match ty {
+        DataType::Any | DataType::Primitive(_) | DataType::Literal(_) | DataType::Generic(_) => {}
+        DataType::List(list) => visitor.visit_datatype_mut(&mut list.ty),
+        DataType::Nullable(ty) => visitor.visit_datatype_mut(ty),
+        DataType::Map(ty) | DataType::Result(ty) | DataType::Conversion(ty) => {
+            visitor.visit_datatype_mut(&mut ty.0);
+            visitor.visit_datatype_mut(&mut ty.1);
+        }
+        DataType::Struct(ty) => visitor.visit_struct_mut(ty),
+        DataType::Enum(ty) => visitor.visit_enum_mut(ty),
+        DataType::Tuple(ty) => {
+            for ty in &mut ty.fields {
+                visitor.visit_datatype_mut(ty);
+            }
+        }
+        DataType::Reference(reference) => visitor.visit_reference_mut(reference),
+    }
+}
+
+/// Visit the children of the struct, mutably.
+pub fn walk_struct_mut<V: VisitorMut + ?Sized>(visitor: &mut V, ty: &mut StructType) {
+    match &mut ty.fields {
+        StructFields::Unit => {}
+        StructFields::Unnamed(fields) => walk_unnamed_fields_mut(visitor, fields),
+        StructFields::Named(fields) => walk_named_fields_mut(visitor, fields),
+    }
+}
+
+/// Visit the children of the enum, mutably.
+pub fn walk_enum_mut<V: VisitorMut + ?Sized>(visitor: &mut V, ty: &mut EnumType) {
+    for (name, variant) in &mut ty.variants {
+        visitor.visit_variant_mut(name, variant);
+    }
+}
+
+/// Visit the children of the enum variant, mutably.
+pub fn walk_variant_mut<V: VisitorMut + ?Sized>(visitor: &mut V, variant: &mut EnumVariant) {
+    match &mut variant.inner {
+        EnumVariants::Unit => {}
+        EnumVariants::Unnamed(fields) => walk_unnamed_fields_mut(visitor, fields),
+        EnumVariants::Named(fields) => walk_named_fields_mut(visitor, fields),
+    }
+}
+
+/// Visit the children of the field, mutably.
+pub fn walk_field_mut<V: VisitorMut + ?Sized>(visitor: &mut V, field: &mut Field) {
+    visitor.visit_datatype_mut(&mut field.ty);
+}
+
+/// Visit the children of the reference, mutably.
+pub fn walk_reference_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    reference: &mut DataTypeReference,
+) {
+    for ty in &mut reference.generics {
+        visitor.visit_datatype_mut(ty);
+    }
+}
+
+fn walk_unnamed_fields_mut<V: VisitorMut + ?Sized>(visitor: &mut V, fields: &mut UnnamedFields) {
+    for field in &mut fields.fields {
+        visitor.visit_field_mut(None, field);
+    }
+}
+
+fn walk_named_fields_mut<V: VisitorMut + ?Sized>(visitor: &mut V, fields: &mut NamedFields) {
+    for (name, field) in &mut fields.fields {
+        visitor.visit_field_mut(Some(name), field);
+    }
+}
+
+/// Visit every named type in the `type_map` by mutable reference.
+pub fn visit_type_map_mut<V: VisitorMut + ?Sized>(visitor: &mut V, type_map: &mut TypeMap) {
+    for ty in type_map.values_mut().flatten() {
+        visitor.visit_named_datatype_mut(ty);
+    }
+}
@@ -0,0 +1,175 @@
+use std::{borrow::Cow, collections::BTreeSet};
+
+use crate::{
+    datatype::visit::{visit_with_references, walk_reference, Visitor},
+    DataTypeReference, NamedDataType, NamedType, SpectaID, TypeMap,
+};
+
+/// Selects named types by their [`SpectaID`], name or module path.
+///
+/// Names and module paths are matched as patterns where `*` matches any number of characters within a single segment.
+/// A module path segment of `**` matches any number of segments (including none), so `my_crate::admin::**` matches every type defined in `my_crate::admin` or any of it's submodules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TypeSelector {
+    /// The type with the given [`SpectaID`].
+    Sid(SpectaID),
+    /// Types with an exported name matching the pattern. This is the name after `#[serde(rename = "...")]` is applied.
+    Name(Cow<'static, str>),
+    /// Types defined in a module with a path matching the pattern. Eg. `my_crate::api::*`.
+    Module(Cow<'static, str>),
+}
+
+impl TypeSelector {
+    /// Select the type `T`.
+    pub fn of<T: NamedType>() -> Self {
+        Self::Sid(T::SID)
+    }
+
+    /// Select types by their exported name.
+    pub fn name(pattern: impl Into<Cow<'static, str>>) -> Self {
+        Self::Name(pattern.into())
+    }
+
+    /// Select types by the module they are defined in.
+    pub fn module(pattern: impl Into<Cow<'static, str>>) -> Self {
+        Self::Module(pattern.into())
+    }
+
+    /// Check if the type is selected.
+    pub fn matches(&self, ty: &NamedDataType) -> bool {
+        match self {
+            Self::Sid(sid) => ty.ext().map(|ext| ext.sid) == Some(*sid),
+            Self::Name(pattern) => matches_segment(pattern, &ty.name),
+            Self::Module(pattern) => ty.ext().is_some_and(|ext| {
+                matches_path(
+                    &pattern.split("::").collect::<Vec<_>>(),
+                    &ext.module_path.split("::").collect::<Vec<_>>(),
+                )
+            }),
+        }
+    }
+}
+
+impl From<SpectaID> for TypeSelector {
+    fn from(sid: SpectaID) -> Self {
+        Self::Sid(sid)
+    }
+}
+
+/// Filters the types which are exported by [`export::ts_with_cfg`](crate::export::ts_with_cfg).
+///
+/// When no roots are configured every type which is exported by default is selected, otherwise only the roots and the types they reference (directly or through other types) are.
+/// Excluded types are removed last, so an excluded type is never exported even when it's referenced by a root. References to it are left as is, which is useful when it's exported as part of another bundle.
+///
+/// ```rust
+/// use specta::{export::{ExportFilter, TypeSelector}, Type};
+///
+/// #[derive(Type)]
+/// pub struct User {
+///     pub id: i32,
+/// }
+///
+/// let filter = ExportFilter::new()
+///     .root(TypeSelector::of::<User>())
+///     .root(TypeSelector::module("my_crate::sdk::**"))
+///     .exclude(TypeSelector::name("*Internal"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExportFilter {
+    roots: Vec<TypeSelector>,
+    exclude: Vec<TypeSelector>,
+}
+
+impl ExportFilter {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Select the types matching `selector` along with every type they depend on.
+    pub fn root(mut self, selector: impl Into<TypeSelector>) -> Self {
+        self.roots.push(selector.into());
+        self
+    }
+
+    /// Never export the types matching `selector`.
+    pub fn exclude(mut self, selector: impl Into<TypeSelector>) -> Self {
+        self.exclude.push(selector.into());
+        self
+    }
+
+    /// Whether any roots have been configured.
+    pub fn has_roots(&self) -> bool {
+        !self.roots.is_empty()
+    }
+
+    /// Apply the filter to `types` returning the selected types.
+    ///
+    /// Roots are only matched against `types` so a type which isn't exported (Eg. `#[specta(export = false)]`) is never a root itself.
+    /// `type_map` is used to resolve the dependencies of the roots so it should contain every type which could be referenced, not just the ones which are exported by default.
+    pub fn apply(&self, types: &TypeMap, type_map: &TypeMap) -> TypeMap {
+        let mut selected = match self.has_roots() {
+            true => {
+                let mut references = References::default();
+                for ty in types.values().flatten() {
+                    if self.roots.iter().any(|root| root.matches(ty)) {
+                        if let Some(ext) = ty.ext() {
+                            references.0.insert(ext.sid);
+                        }
+                        visit_with_references(&mut references, &ty.inner, type_map);
+                    }
+                }
+
+                type_map
+                    .iter()
+                    .filter(|(sid, _)| references.0.contains(sid))
+                    .map(|(sid, ty)| (*sid, ty.clone()))
+                    .collect()
+            }
+            false => types.clone(),
+        };
+
+        selected.retain(|_, ty| match ty {
+            Some(ty) => !self.exclude.iter().any(|exclude| exclude.matches(ty)),
+            None => true,
+        });
+
+        selected
+    }
+}
+
+/// Collects the [`SpectaID`]s of every type which is referenced.
+#[derive(Default)]
+pub(super) struct References(pub(super) BTreeSet<SpectaID>);
+
+impl Visitor for References {
+    fn visit_reference(&mut self, reference: &DataTypeReference) {
+        self.0.insert(reference.sid);
+        walk_reference(self, reference)
+    }
+}
+
+fn matches_path(pattern: &[&str], path: &[&str]) -> bool {
+    match (pattern.split_first(), path.split_first()) {
+        (Some((&"**", rest)), _) => {
+            matches_path(rest, path) || (!path.is_empty() && matches_path(pattern, &path[1..]))
+        }
+        (Some((segment, rest)), Some((part, path))) => {
+            matches_segment(segment, part) && matches_path(rest, path)
+        }
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn matches_segment(pattern: &str, value: &str) -> bool {
+    match pattern.split_once('*') {
+        Some((prefix, rest)) => {
+            value.starts_with(prefix)
+                && (0..=value.len() - prefix.len())
+                    .filter(|i| value.is_char_boundary(prefix.len() + i))
+                    .any(|i| matches_segment(rest, &value[prefix.len() + i..]))
+        }
+        None => pattern == value,
+    }
+}
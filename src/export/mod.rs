@@ -1,9 +1,13 @@
 mod export;
+mod filter;
+mod order;
 #[cfg(feature = "typescript")]
 #[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
 mod ts;
 
 pub use export::*;
+pub use filter::*;
+pub use order::*;
 #[cfg(feature = "typescript")]
 #[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
 pub use ts::*;
@@ -0,0 +1,98 @@
+use std::collections::BTreeSet;
+
+use crate::{datatype::visit::Visitor, NamedDataType, SpectaID, TypeMap};
+
+use super::filter::References;
+
+/// The order in which types are written by [`export::ts_with_cfg`](crate::export::ts_with_cfg).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExportOrder {
+    /// Order by [`SpectaID`]. This is roughly by the Rust name of the type but isn't stable between builds when two types share the same name.
+    #[default]
+    Sid,
+    /// Order alphabetically by the exported name of the type.
+    Alphabetical,
+    /// Order so every type comes after the types it references. Types which don't depend on each other are ordered alphabetically.
+    ///
+    /// This is required for languages where a type must be declared before it is used (Eg. Python, Go or Swift).
+    /// Types which reference each other can't be ordered this way so cycles are broken at a deterministic but otherwise arbitrary point.
+    Topological,
+    /// Group types by the module they are defined in. The modules and the types within them are ordered alphabetically.
+    Module,
+}
+
+impl ExportOrder {
+    /// Sort the types in the `type_map` according to this order.
+    pub fn sort<'a>(&self, type_map: &'a TypeMap) -> Vec<&'a NamedDataType> {
+        let mut types = type_map.values().flatten().collect::<Vec<_>>();
+
+        match self {
+            Self::Sid => {}
+            Self::Alphabetical => types.sort_by(|a, b| by_name(a, b)),
+            Self::Topological => {
+                types.sort_by(|a, b| by_name(a, b));
+
+                let mut visited = BTreeSet::new();
+                let mut sorted = Vec::with_capacity(types.len());
+                for ty in &types {
+                    visit_dependencies(ty, type_map, &mut visited, &mut sorted);
+                }
+                types = sorted;
+            }
+            Self::Module => types.sort_by(|a, b| {
+                module_path(a)
+                    .cmp(module_path(b))
+                    .then_with(|| by_name(a, b))
+            }),
+        }
+
+        types
+    }
+}
+
+fn by_name(a: &NamedDataType, b: &NamedDataType) -> std::cmp::Ordering {
+    a.name().cmp(b.name()).then_with(|| sid(a).cmp(&sid(b)))
+}
+
+fn sid(ty: &NamedDataType) -> Option<SpectaID> {
+    ty.ext().map(|ext| ext.sid)
+}
+
+fn module_path(ty: &NamedDataType) -> &str {
+    ty.ext()
+        .as_ref()
+        .map(|ext| ext.module_path.as_ref())
+        .unwrap_or_default()
+}
+
+// A depth-first search which adds each type after all of it's dependencies.
+// Types are marked as visited before their dependencies so cycles terminate.
+fn visit_dependencies<'a>(
+    ty: &'a NamedDataType,
+    type_map: &'a TypeMap,
+    visited: &mut BTreeSet<Option<SpectaID>>,
+    sorted: &mut Vec<&'a NamedDataType>,
+) {
+    // Types without a `SpectaID` can't be referenced so they never need to be deduplicated.
+    let sid = sid(ty);
+    if sid.is_some() && !visited.insert(sid) {
+        return;
+    }
+
+    let mut references = References::default();
+    references.visit_named_datatype(ty);
+
+    let mut dependencies = references
+        .0
+        .iter()
+        .filter_map(|sid| type_map.get(sid)?.as_ref())
+        .collect::<Vec<_>>();
+    dependencies.sort_by(|a, b| by_name(a, b));
+
+    for dependency in dependencies {
+        visit_dependencies(dependency, type_map, visited, sorted);
+    }
+
+    sorted.push(ty);
+}
@@ -1,22 +1,140 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, io};
 
-use crate::ts::{self, ExportConfig, ExportError};
+use crate::{
+    ts::{self, ExportConfig, ExportError},
+    OutputDiff, SerdeMode, TypeMap,
+};
 
 use super::get_types;
 
+// Formatting the file changes it's content so we can't compare it to what we would write.
+// Instead we mark the file with a hash of the unformatted content.
+const HASH_PREFIX: &str = "// Specta hash: ";
+
 /// Exports all types in the [`TYPES`](static@crate::export::TYPES) map to the provided TypeScript file.
-pub fn ts(path: &str) -> Result<(), ExportError> {
+///
+/// Returns `true` if the file was written or `false` if it was already up to date. Refer to [`ts_with_cfg`] for more information.
+pub fn ts(path: &str) -> Result<bool, ExportError> {
     ts_with_cfg(path, &ExportConfig::default())
 }
 
 /// Exports all types in the [`TYPES`](static@crate::export::TYPES) map to the provided TypeScript file but allow you to provide a configuration for the exporter.
-pub fn ts_with_cfg(path: &str, conf: &ExportConfig) -> Result<(), ExportError> {
-    let mut out = "// This file has been generated by Specta. DO NOT EDIT.\n\n".to_string();
+///
+/// The file is only written (and [formatted](ExportConfig::formatter)) if it's content would change, so file watchers aren't triggered by every build.
+/// Returns `true` if the file was written or `false` if it was already up to date.
+///
+/// When a formatter is configured a hash of the unformatted output is added to the header of the file to detect if it's up to date.
+pub fn ts_with_cfg(path: &str, conf: &ExportConfig) -> Result<bool, ExportError> {
+    let out = file_content(conf)?;
+
+    if read_existing(path)?.is_some_and(|existing| is_up_to_date(&existing, conf, &out)) {
+        return Ok(false);
+    }
+
+    std::fs::write(path, out)?;
+    conf.run_format(path.into())?;
+    Ok(true)
+}
+
+/// Check the provided TypeScript file is up to date with the types in the [`TYPES`](static@crate::export::TYPES) map without writing to it.
+///
+/// Refer to [`ts_check_with_cfg`] for more information.
+pub fn ts_check(path: &str) -> Result<(), ExportError> {
+    ts_check_with_cfg(path, &ExportConfig::default())
+}
+
+/// Check the provided TypeScript file is up to date with the types in the [`TYPES`](static@crate::export::TYPES) map without writing to it.
+///
+/// This is intended for CI when the exported file is committed to the repository. The `conf` should be the same as the one passed to [`ts_with_cfg`].
+/// If the file is missing or out of date [`ExportError::OutOfDate`] is returned with a diff of the changes which exporting would make.
+/// The file is compared line by line so it's not out of date if only the line endings differ (Eg. `\r\n` on Windows).
+///
+/// When a formatter is configured only the hash in the header of the file is compared, so the diff will also contain the changes made by the formatter.
+pub fn ts_check_with_cfg(path: &str, conf: &ExportConfig) -> Result<(), ExportError> {
+    let out = file_content(conf)?;
+    let existing = read_existing(path)?;
+
+    match existing {
+        Some(existing) if is_up_to_date(&existing, conf, &out) => Ok(()),
+        existing => Err(ExportError::OutOfDate(
+            path.to_string(),
+            OutputDiff::new(existing.as_deref().unwrap_or_default(), &out),
+        )),
+    }
+}
+
+/// Exports all types in the [`TYPES`](static@crate::export::TYPES) map to a string.
+///
+/// The result is the [header](ExportConfig::header) followed by the types. Unlike [`ts_with_cfg`] the [formatter](ExportConfig::formatter) isn't run so no hash is added to the header.
+pub fn ts_to_string(conf: &ExportConfig) -> Result<String, ExportError> {
+    Ok(with_header(conf, &render(conf)?, false))
+}
+
+/// Exports all types in the [`TYPES`](static@crate::export::TYPES) map to the provided writer.
+///
+/// Refer to [`ts_to_string`] for more information.
+pub fn ts_to_writer(writer: &mut impl io::Write, conf: &ExportConfig) -> Result<(), ExportError> {
+    writer.write_all(ts_to_string(conf)?.as_bytes())?;
+    Ok(())
+}
+
+// The content of the file written by `ts_with_cfg` before it's formatted.
+fn file_content(conf: &ExportConfig) -> Result<String, ExportError> {
+    Ok(with_header(conf, &render(conf)?, conf.formatter.is_some()))
+}
+
+fn with_header(conf: &ExportConfig, types: &str, with_hash: bool) -> String {
+    let mut out = conf.header.to_string();
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+    if with_hash {
+        out += &format!("{HASH_PREFIX}{:016x}\n", hash(&format!("{out}{types}")));
+    }
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out += types;
+    out
+}
+
+fn read_existing(path: &str) -> Result<Option<String>, ExportError> {
+    match std::fs::read_to_string(path) {
+        Ok(existing) => Ok(Some(existing)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn is_up_to_date(existing: &str, conf: &ExportConfig, out: &str) -> bool {
+    match conf.formatter {
+        Some(_) => {
+            let hash = out.lines().find(|line| line.starts_with(HASH_PREFIX));
+            existing.lines().find(|line| line.starts_with(HASH_PREFIX)) == hash
+        }
+        // Git can change the line endings (Eg. `core.autocrlf`) so we compare the lines like the `OutputDiff`.
+        None => existing.lines().eq(out.lines()),
+    }
+}
+
+// FNV-1a, the same as the `SpectaID` hash. `DefaultHasher` isn't guaranteed to be stable between Rust versions.
+fn hash(content: &str) -> u64 {
+    content.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x00000100000001B3)
+    })
+}
+
+/// Render all the exported types without the header.
+fn render(conf: &ExportConfig) -> Result<String, ExportError> {
+    let mut out = String::new();
 
     let export_by_default = conf.export_by_default.unwrap_or(true);
 
+    let type_map = get_types().collect::<TypeMap>();
+
     // We sort by name to detect duplicate types BUT also to ensure the output is deterministic. The SID can change between builds so is not suitable for this.
-    let types = get_types()
+    let types = type_map
+        .iter()
         .filter(|(_, v)| match v {
             Some(v) => v
                 .ext()
@@ -27,7 +145,11 @@ pub fn ts_with_cfg(path: &str, conf: &ExportConfig) -> Result<(), ExportError> {
                 unreachable!("Placeholder type should never be returned from the Specta functions!")
             }
         })
-        .collect::<BTreeMap<_, _>>();
+        .map(|(sid, v)| (*sid, v.clone()))
+        .collect::<TypeMap>();
+    let types = conf.filter.apply(&types, &type_map);
+
+    let mut errors = Vec::new();
 
     // This is a clone of `detect_duplicate_type_names` but using a `BTreeMap` for deterministic ordering
     let mut map = BTreeMap::new();
@@ -39,11 +161,16 @@ pub fn ts_with_cfg(path: &str, conf: &ExportConfig) -> Result<(), ExportError> {
                         map.insert(dt.name.clone(), (sid, ext.impl_location))
                     {
                         if existing_sid != sid {
-                            return Err(ExportError::DuplicateTypeName(
+                            let err = ExportError::DuplicateTypeName(
                                 dt.name.clone(),
                                 ext.impl_location,
                                 existing_impl_location,
-                            ));
+                            );
+
+                            match conf.collect_errors {
+                                true => errors.push(err),
+                                false => return Err(err),
+                            }
                         }
                     }
                 }
@@ -52,17 +179,43 @@ pub fn ts_with_cfg(path: &str, conf: &ExportConfig) -> Result<(), ExportError> {
         }
     }
 
-    for (_, typ) in types.iter() {
-        out += &ts::export_named_datatype(
-            conf,
-            match typ {
-                Some(v) => v,
-                None => unreachable!(),
-            },
-            &types,
-        )?;
-        out += "\n\n";
+    // When suffixing is enabled without picking a direction we export both the `Input` and `Output` variant of each type.
+    // They share the warning handler so a warning for both variants is only reported once.
+    let conf = &*conf.with_deduplicated_warnings();
+    let confs = match (conf.serde_mode_suffix, conf.serde_mode) {
+        (true, None) => vec![
+            conf.clone().serde_mode(Some(SerdeMode::Deserialize)),
+            conf.clone().serde_mode(Some(SerdeMode::Serialize)),
+        ],
+        _ => vec![conf.clone()],
+    };
+
+    for typ in conf.order.sort(&types) {
+        for conf in &confs {
+            // Types which are filtered out can still be referenced so we resolve against every type.
+            let result = ts::export_named_datatype(conf, typ, &type_map);
+
+            match result {
+                Ok(ts) => {
+                    out += &ts;
+                    out += "\n\n";
+                }
+                Err(err) if conf.collect_errors => {
+                    // Types exported for both `SerdeMode`s would otherwise report each error twice.
+                    for err in err.into_errors() {
+                        if !errors.contains(&err) {
+                            errors.push(err);
+                        }
+                    }
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(ExportError::Multiple(errors));
     }
 
-    std::fs::write(path, out).map_err(Into::into)
+    Ok(out)
 }
@@ -10,41 +10,109 @@ pub use ctor;
 #[cfg(feature = "functions")]
 pub use specta_macros::fn_datatype;
 
+/// A value serialized by `serde_json`. Eg. the default value or example of a field.
+#[cfg(feature = "serde_json")]
+pub type SerializedValue = serde_json::Value;
+/// A value serialized by `serde_json`. There is nothing to serialize into without the `serde_json` feature.
+#[cfg(not(feature = "serde_json"))]
+pub type SerializedValue = ();
+
 /// Functions used to construct `crate::datatype` types (they have private fields so can't be constructed directly).
 /// We intentionally keep their fields private so we can modify them without a major version bump.
 /// As this module is `#[doc(hidden)]` we allowed to make breaking changes within a minor version as it's only used by the macros.
 pub mod construct {
     use std::borrow::Cow;
 
-    use crate::{datatype::*, ImplLocation, SpectaID};
+    use crate::{datatype::*, ImplLocation, SpectaID, StableID};
 
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(not(feature = "serde_json"), allow(unused_variables))]
     pub const fn field(
-        skip: bool,
+        skip_serializing: bool,
+        skip_deserializing: bool,
         optional: bool,
+        default: bool,
+        default_value: Option<super::SerializedValue>,
+        skip_serializing_if: bool,
         flatten: bool,
+        aliases: Vec<Cow<'static, str>>,
+        readonly: Option<bool>,
+        constraints: FieldConstraints,
+        example: Option<super::SerializedValue>,
         deprecated: Option<DeprecatedType>,
+        metadata: Metadata,
         docs: Cow<'static, str>,
         ty: DataType,
     ) -> Field {
         Field {
-            skip,
+            skip_serializing,
+            skip_deserializing,
             optional,
+            default,
+            #[cfg(feature = "serde_json")]
+            default_value,
+            skip_serializing_if,
             flatten,
+            aliases,
+            readonly,
+            constraints,
+            #[cfg(feature = "serde_json")]
+            example,
             deprecated,
+            metadata,
             docs,
             ty,
         }
     }
 
+    pub const fn metadata(entries: Vec<(Cow<'static, str>, Cow<'static, str>)>) -> Metadata {
+        Metadata(entries)
+    }
+
+    pub const fn field_constraints(
+        min: Option<f64>,
+        max: Option<f64>,
+        min_length: Option<usize>,
+        max_length: Option<usize>,
+        pattern: Option<Cow<'static, str>>,
+    ) -> FieldConstraints {
+        FieldConstraints {
+            min,
+            max,
+            min_length,
+            max_length,
+            pattern,
+        }
+    }
+
+    /// Apply `#[specta(format = "...")]` to a field. Anything other than a string, optional string or list of strings is left untouched.
+    pub fn string_format(ty: DataType, format: &'static str) -> DataType {
+        match ty {
+            DataType::Primitive(PrimitiveType::String | PrimitiveType::FormattedString(_)) => {
+                StringFormat::new(format).into()
+            }
+            DataType::Nullable(ty) => DataType::Nullable(Box::new(string_format(*ty, format))),
+            DataType::List(list) => DataType::List(ListType {
+                ty: Box::new(string_format(*list.ty, format)),
+                ..list
+            }),
+            ty => ty,
+        }
+    }
+
     pub const fn r#struct(
         name: Cow<'static, str>,
         generics: Vec<GenericType>,
         fields: StructFields,
+        deny_unknown_fields: bool,
+        readonly: Option<bool>,
     ) -> StructType {
         StructType {
             name,
             generics,
             fields,
+            deny_unknown_fields,
+            readonly,
         }
     }
 
@@ -68,25 +136,38 @@ pub mod construct {
         repr: EnumRepr,
         generics: Vec<GenericType>,
         variants: Vec<(Cow<'static, str>, EnumVariant)>,
+        deny_unknown_fields: bool,
+        readonly: Option<bool>,
     ) -> EnumType {
         EnumType {
             name,
             repr,
             generics,
             variants,
+            deny_unknown_fields,
+            readonly,
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub const fn enum_variant(
-        skip: bool,
+        skip_serializing: bool,
+        skip_deserializing: bool,
+        other: bool,
+        aliases: Vec<Cow<'static, str>>,
         deprecated: Option<DeprecatedType>,
+        metadata: Metadata,
         docs: Cow<'static, str>,
         inner: EnumVariants,
     ) -> EnumVariant {
         EnumVariant {
-            skip,
+            skip_serializing,
+            skip_deserializing,
+            other,
+            aliases,
             docs,
             deprecated,
+            metadata,
             inner,
         }
     }
@@ -106,12 +187,18 @@ pub mod construct {
         EnumVariants::Named(NamedFields { fields, tag })
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(not(feature = "serde_json"), allow(unused_variables))]
     pub const fn named_data_type(
         name: Cow<'static, str>,
         docs: Cow<'static, str>,
         deprecated: Option<DeprecatedType>,
+        example: Option<super::SerializedValue>,
+        metadata: Metadata,
         sid: SpectaID,
         impl_location: ImplLocation,
+        stable_id: StableID,
+        module_path: &'static str,
         export: Option<bool>,
         inner: DataType,
     ) -> NamedDataType {
@@ -119,15 +206,32 @@ pub mod construct {
             name,
             docs,
             deprecated,
+            #[cfg(feature = "serde_json")]
+            example,
+            metadata,
             ext: Some(NamedDataTypeExt {
                 sid,
                 impl_location,
+                stable_id,
+                module_path: Cow::Borrowed(module_path),
                 export,
             }),
             inner,
         }
     }
 
+    /// Serialize the value of a `#[specta(example = ...)]` attribute.
+    #[cfg(feature = "serde_json")]
+    pub fn example<T: serde::Serialize>(value: T) -> Option<super::SerializedValue> {
+        serde_json::to_value(value).ok()
+    }
+
+    /// Serialize the value of a `#[specta(example = ...)]` attribute.
+    #[cfg(not(feature = "serde_json"))]
+    pub fn example<T>(_value: T) -> Option<super::SerializedValue> {
+        None
+    }
+
     pub const fn data_type_reference(
         name: Cow<'static, str>,
         sid: SpectaID,
@@ -148,6 +252,10 @@ pub mod construct {
         ImplLocation(loc)
     }
 
+    pub const fn stable_id(id: &'static str) -> StableID {
+        StableID(Cow::Borrowed(id))
+    }
+
     /// Compute an SID hash for a given type.
     /// This will produce a type hash from the arguments.
     /// This hashing function was derived from https://stackoverflow.com/a/71464396
@@ -175,3 +283,52 @@ pub mod construct {
         SpectaID { type_name, hash }
     }
 }
+
+/// Capture the default value of a field with `#[serde(default)]`.
+///
+/// This uses [autoref-specialization](https://github.com/dtolnay/case-studies/blob/master/autoref-specialization/README.md) so fields which don't implement `Default` or `Serialize` (or when the `serde_json` feature is disabled) still compile and just don't have a default value.
+pub mod default_value {
+    use std::marker::PhantomData;
+
+    use super::SerializedValue as Value;
+
+    /// Probe for `#[serde(default)]` which uses the `Default` implementation of `T`.
+    pub struct DefaultProbe<T>(PhantomData<T>);
+
+    impl<T> DefaultProbe<T> {
+        #[allow(clippy::new_without_default)]
+        pub const fn new() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    /// Probe for `#[serde(default = "path")]` which uses the provided function.
+    pub struct DefaultFnProbe<T>(pub fn() -> T);
+
+    pub trait ViaSerialize {
+        fn default_value(&self) -> Option<Value>;
+    }
+
+    #[cfg(feature = "serde_json")]
+    impl<T: Default + serde::Serialize> ViaSerialize for &DefaultProbe<T> {
+        fn default_value(&self) -> Option<Value> {
+            serde_json::to_value(T::default()).ok()
+        }
+    }
+
+    #[cfg(feature = "serde_json")]
+    impl<T: serde::Serialize> ViaSerialize for &DefaultFnProbe<T> {
+        fn default_value(&self) -> Option<Value> {
+            serde_json::to_value((self.0)()).ok()
+        }
+    }
+
+    pub trait ViaFallback {
+        fn default_value(&self) -> Option<Value> {
+            None
+        }
+    }
+
+    impl<T> ViaFallback for DefaultProbe<T> {}
+    impl<T> ViaFallback for DefaultFnProbe<T> {}
+}
@@ -3,16 +3,21 @@ use crate::*;
 pub use super::ts::*;
 
 pub fn format_comment(cfg: &ExportConfig, typ: &NamedDataType, type_map: &TypeMap) -> Output {
-    format_comment_inner(
+    let errors = Default::default();
+    let result = format_comment_inner(
         &ExportContext {
             cfg,
             path: vec![],
             // TODO: Should JS doc support per field or variant comments???
             is_export: false,
+            readonly: cfg.readonly,
+            errors: &errors,
         },
         typ,
         type_map,
-    )
+    );
+
+    with_collected_errors(cfg, errors, result)
 }
 
 fn format_comment_inner(
@@ -1,3 +1,9 @@
+mod export_path;
+mod output_diff;
+
+pub use export_path::*;
+pub use output_diff::*;
+
 // /// Alpha: [OpenAPI](https://www.openapis.org) language exporter.
 // #[cfg(feature = "openapi")]
 // #[cfg_attr(docsrs, doc(cfg(feature = "openapi")))]
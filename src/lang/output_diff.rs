@@ -0,0 +1,135 @@
+use std::fmt;
+
+/// The number of unchanged lines shown around each change when displaying an [`OutputDiff`].
+const CONTEXT: usize = 2;
+
+/// Beyond this size the middle of the diff isn't minimized to avoid quadratic memory usage.
+const MAX_TABLE_SIZE: usize = 4_000_000;
+
+/// A line in an [`OutputDiff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    Unchanged(String),
+    /// The line is only in the old content.
+    Removed(String),
+    /// The line is only in the new content.
+    Added(String),
+}
+
+/// A line based diff between the old and new content of an exported file.
+///
+/// It's [`Display`](fmt::Display) implementation renders the changed lines, prefixed with `-` or `+`, along with a few lines of context like `diff -u`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutputDiff {
+    pub lines: Vec<DiffLine>,
+}
+
+impl OutputDiff {
+    pub fn new(old: &str, new: &str) -> Self {
+        let old = old.lines().collect::<Vec<_>>();
+        let new = new.lines().collect::<Vec<_>>();
+
+        // Generated files usually only change in a few places so we only run the expensive part on the lines in between.
+        let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+        let suffix = old[prefix..]
+            .iter()
+            .rev()
+            .zip(new[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        let mut lines = old[..prefix]
+            .iter()
+            .map(|line| DiffLine::Unchanged(line.to_string()))
+            .collect::<Vec<_>>();
+        diff_lines(
+            &old[prefix..old.len() - suffix],
+            &new[prefix..new.len() - suffix],
+            &mut lines,
+        );
+        lines.extend(
+            old[old.len() - suffix..]
+                .iter()
+                .map(|line| DiffLine::Unchanged(line.to_string())),
+        );
+
+        Self { lines }
+    }
+
+    /// Whether the old and new content are the same.
+    pub fn is_empty(&self) -> bool {
+        self.lines
+            .iter()
+            .all(|line| matches!(line, DiffLine::Unchanged(_)))
+    }
+}
+
+impl fmt::Display for OutputDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let changed = self
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| !matches!(line, DiffLine::Unchanged(_)))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        let mut last_shown = None;
+        for (i, line) in self.lines.iter().enumerate() {
+            let near_change = changed
+                .iter()
+                .any(|&change| i + CONTEXT >= change && i <= change + CONTEXT);
+            if !near_change {
+                continue;
+            }
+
+            if last_shown.is_some_and(|last| last + 1 != i) {
+                writeln!(f, "...")?;
+            }
+            last_shown = Some(i);
+
+            match line {
+                DiffLine::Unchanged(line) => writeln!(f, " {line}")?,
+                DiffLine::Removed(line) => writeln!(f, "-{line}")?,
+                DiffLine::Added(line) => writeln!(f, "+{line}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Diff using the longest common subsequence of the lines.
+fn diff_lines(old: &[&str], new: &[&str], lines: &mut Vec<DiffLine>) {
+    if old.len().saturating_mul(new.len()) > MAX_TABLE_SIZE {
+        lines.extend(old.iter().map(|line| DiffLine::Removed(line.to_string())));
+        lines.extend(new.iter().map(|line| DiffLine::Added(line.to_string())));
+        return;
+    }
+
+    // `table[i][j]` is the length of the longest common subsequence of `old[i..]` and `new[j..]`.
+    let mut table = vec![vec![0u32; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            table[i][j] = match old[i] == new[j] {
+                true => table[i + 1][j + 1] + 1,
+                false => table[i + 1][j].max(table[i][j + 1]),
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(DiffLine::Unchanged(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || table[i + 1][j] >= table[i][j + 1]) {
+            lines.push(DiffLine::Removed(old[i].to_string()));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(new[j].to_string()));
+            j += 1;
+        }
+    }
+}
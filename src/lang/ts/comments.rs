@@ -1,6 +1,6 @@
 use std::{borrow::Cow, iter};
 
-use crate::DeprecatedType;
+use crate::{DeprecatedType, FieldConstraints};
 
 use super::{CommentFormatterArgs, CommentFormatterFn};
 
@@ -9,7 +9,23 @@ const _: CommentFormatterFn = js_doc;
 
 /// Converts Typescript comments into JSDoc comments.
 pub fn js_doc(arg: CommentFormatterArgs) -> String {
-    js_doc_internal(arg.docs, arg.deprecated, iter::empty())
+    let tags = arg.constraints.into_iter().flat_map(constraint_tags);
+    #[cfg(feature = "serde_json")]
+    let tags = tags
+        .chain(arg.default_value.map(|v| format!("@default {v}")))
+        .chain(arg.example.map(|v| format!("@example {v}")));
+
+    js_doc_internal(arg.docs, arg.deprecated, tags)
+}
+
+/// The JSDoc tags for the validation rules of a field. Eg. `@minimum 0`
+fn constraint_tags(constraints: &FieldConstraints) -> impl Iterator<Item = String> + '_ {
+    iter::empty()
+        .chain(constraints.min().map(|v| format!("@minimum {v}")))
+        .chain(constraints.max().map(|v| format!("@maximum {v}")))
+        .chain(constraints.min_length().map(|v| format!("@minLength {v}")))
+        .chain(constraints.max_length().map(|v| format!("@maxLength {v}")))
+        .chain(constraints.pattern().map(|v| format!("@pattern {v}")))
 }
 
 pub(crate) fn js_doc_internal(
@@ -17,7 +33,8 @@ pub(crate) fn js_doc_internal(
     deprecated: Option<&DeprecatedType>,
     extra_lines: impl Iterator<Item = String>,
 ) -> String {
-    if docs.is_empty() && deprecated.is_none() {
+    let mut extra_lines = extra_lines.peekable();
+    if docs.is_empty() && deprecated.is_none() && extra_lines.peek().is_none() {
         return "".into();
     }
 
@@ -48,7 +65,11 @@ pub(crate) fn js_doc_internal(
         comment.push('\n');
     }
 
-    comment.extend(extra_lines);
+    for line in extra_lines {
+        comment.push_str(" * ");
+        comment.push_str(&line);
+        comment.push('\n');
+    }
     comment.push_str(" */\n");
 
     comment
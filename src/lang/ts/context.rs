@@ -1,13 +1,8 @@
-use std::{borrow::Cow, fmt};
+use std::cell::RefCell;
 
-use super::ExportConfig;
+use crate::{EnumVariant, ExportPath, Field, PathItem};
 
-#[derive(Clone, Debug)]
-pub(crate) enum PathItem {
-    Type(Cow<'static, str>),
-    Field(Cow<'static, str>),
-    Variant(Cow<'static, str>),
-}
+use super::{ExportConfig, ExportError, ExportWarning};
 
 #[derive(Clone)]
 pub(crate) struct ExportContext<'a> {
@@ -15,6 +10,10 @@ pub(crate) struct ExportContext<'a> {
     pub(crate) path: Vec<PathItem>,
     // `false` when inline'ing and `true` when exporting as named.
     pub(crate) is_export: bool,
+    // Whether the types being rendered should be immutable. Starts as `ExportConfig::readonly` but can be overridden by fields.
+    pub(crate) readonly: bool,
+    // Where errors are recorded when `ExportConfig::collect_errors` is enabled.
+    pub(crate) errors: &'a RefCell<Vec<ExportError>>,
 }
 
 impl ExportContext<'_> {
@@ -25,60 +24,56 @@ impl ExportContext<'_> {
         }
     }
 
-    pub(crate) fn export_path(&self) -> ExportPath {
-        ExportPath::new(&self.path)
+    pub(crate) fn with_readonly(&self, readonly: Option<bool>) -> Self {
+        Self {
+            path: self.path.clone(),
+            readonly: readonly.unwrap_or(self.readonly),
+            ..*self
+        }
     }
-}
-
-/// Represents the path of an error in the export tree.
-/// This is designed to be opaque, meaning it's internal format and `Display` impl are subject to change at will.
-pub struct ExportPath(String);
 
-impl ExportPath {
-    pub(crate) fn new(path: &[PathItem]) -> Self {
-        let mut s = String::new();
-        let mut path = path.iter().peekable();
-        while let Some(item) = path.next() {
-            s.push_str(match item {
-                PathItem::Type(v) => v,
-                PathItem::Field(v) => v,
-                PathItem::Variant(v) => v,
-            });
-
-            if let Some(next) = path.peek() {
-                s.push_str(match next {
-                    PathItem::Type(_) => " -> ",
-                    PathItem::Field(_) => ".",
-                    PathItem::Variant(_) => "::",
-                });
-            } else {
-                break;
-            }
+    /// Should the field be omitted for the configured [`SerdeMode`](crate::SerdeMode).
+    pub(crate) fn skip_field(&self, field: &Field) -> bool {
+        match self.cfg.serde_mode {
+            Some(mode) => field.skip_in(mode),
+            None => field.skip(),
         }
+    }
 
-        Self(s)
+    /// Should the variant be omitted for the configured [`SerdeMode`](crate::SerdeMode).
+    pub(crate) fn skip_variant(&self, variant: &EnumVariant) -> bool {
+        match self.cfg.serde_mode {
+            Some(mode) => variant.skip_in(mode),
+            None => variant.skip(),
+        }
     }
 
-    #[doc(hidden)]
-    pub fn new_unsafe(path: &str) -> Self {
-        Self(path.to_string())
+    /// Can the field be missing for the configured [`SerdeMode`](crate::SerdeMode).
+    pub(crate) fn optional_field(&self, field: &Field) -> bool {
+        match self.cfg.serde_mode {
+            Some(mode) => field.optional_in(mode),
+            None => field.optional(),
+        }
     }
-}
 
-impl PartialEq for ExportPath {
-    fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0
+    pub(crate) fn export_path(&self) -> ExportPath {
+        ExportPath::new(&self.path)
     }
-}
 
-impl fmt::Debug for ExportPath {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&self.0)
+    /// Report a warning to the [`ExportConfig::on_warning`] handler.
+    pub(crate) fn warn(&self, warning: impl FnOnce(ExportPath) -> ExportWarning) {
+        if let Some(handler) = &self.cfg.warning_handler {
+            handler.call(warning(self.export_path()));
+        }
     }
-}
 
-impl fmt::Display for ExportPath {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+    /// Report an error. If [`ExportConfig::collect_errors`] is enabled the error is recorded so the caller can keep exporting, otherwise it's returned.
+    pub(crate) fn error(&self, err: ExportError) -> Result<(), ExportError> {
+        if self.cfg.collect_errors {
+            self.errors.borrow_mut().push(err);
+            Ok(())
+        } else {
+            Err(err)
+        }
     }
 }
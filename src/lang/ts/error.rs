@@ -3,7 +3,7 @@ use std::borrow::Cow;
 
 use thiserror::Error;
 
-use crate::{ImplLocation, SerdeError};
+use crate::{ImplLocation, OutputDiff, SerdeError};
 
 use super::ExportPath;
 
@@ -47,6 +47,38 @@ pub enum ExportError {
     Io(#[from] std::io::Error),
     #[error("Failed to export '{0}' due to error: {1}")]
     Other(ExportPath, String),
+    /// Every error found while exporting with [`ExportConfig::collect_errors`](super::ExportConfig::collect_errors) enabled.
+    #[error("{} errors occurred while exporting:\n{}", .0.len(), .0.iter().map(|err| format!(" - {err}")).collect::<Vec<_>>().join("\n"))]
+    Multiple(Vec<ExportError>),
+    /// The file doesn't match what would be exported. Returned by [`export::ts_check`](crate::export::ts_check).
+    #[error("'{0}' is out of date. Export the types again to update it:\n{1}")]
+    OutOfDate(String, OutputDiff),
+}
+
+impl ExportError {
+    /// Get every error contained in this error. This flattens [`ExportError::Multiple`].
+    pub fn into_errors(self) -> Vec<ExportError> {
+        match self {
+            Self::Multiple(errors) => errors.into_iter().flat_map(Self::into_errors).collect(),
+            err => vec![err],
+        }
+    }
+}
+
+/// A problem which doesn't stop the type from being exported but is likely a mistake.
+///
+/// Register a handler with [`ExportConfig::on_warning`](super::ExportConfig::on_warning) to receive them.
+#[derive(Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ExportWarning {
+    #[error("'{0}' was exported as 'any'. Eg. from a `serde_json::Value`")]
+    Any(ExportPath),
+    #[error("'{0}' is a `Result` where both variants export to the same type so they can't be told apart")]
+    ResultCollapsed(ExportPath),
+    #[error("'{0}' is nullable multiple times. Eg. an `Option<Option<T>>` which can't be told apart from `Option<T>`")]
+    NestedNullable(ExportPath),
+    #[error("'{0}' has the generic parameter '{1}' which is never used")]
+    UnusedGeneric(ExportPath, Cow<'static, str>),
 }
 
 // TODO: This `impl` is cringe
@@ -68,6 +100,8 @@ impl PartialEq for ExportError {
             }
             (Self::Io(l0), Self::Io(r0)) => l0.to_string() == r0.to_string(), // This is a bit hacky but it will be fine for usage in unit tests!
             (Self::Other(l0, l1), Self::Other(r0, r1)) => l0 == r0 && l1 == r1,
+            (Self::Multiple(l0), Self::Multiple(r0)) => l0 == r0,
+            (Self::OutOfDate(l0, l1), Self::OutOfDate(r0, r1)) => l0 == r0 && l1 == r1,
             _ => false,
         }
     }
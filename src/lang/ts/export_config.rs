@@ -1,14 +1,77 @@
-use std::{borrow::Cow, io, path::PathBuf};
+use std::{
+    borrow::Cow,
+    collections::BTreeSet,
+    fmt, io,
+    path::PathBuf,
+    sync::{Arc, Mutex, PoisonError},
+};
 
-use crate::DeprecatedType;
+use crate::{
+    DeprecatedType, EnumVariant, Field, FieldConstraints, Metadata, NamedDataType, SerdeMode,
+};
 
-use super::comments;
+use super::{comments, ExportWarning};
 
 #[derive(Debug)]
 #[non_exhaustive]
 pub struct CommentFormatterArgs<'a> {
     pub docs: &'a Cow<'static, str>,
     pub deprecated: Option<&'a DeprecatedType>,
+    /// User defined metadata from `#[specta(meta(key = "value"))]`. Empty if the comment isn't for a type, field or variant.
+    pub metadata: &'a Metadata,
+    /// The validation rules of the field. `None` if the comment isn't for a field.
+    pub constraints: Option<&'a FieldConstraints>,
+    /// The default value of the field. `None` if the comment isn't for a field or it has no known default.
+    #[cfg(feature = "serde_json")]
+    pub default_value: Option<&'a serde_json::Value>,
+    /// An example value of the type or field from `#[specta(example = ...)]`.
+    #[cfg(feature = "serde_json")]
+    pub example: Option<&'a serde_json::Value>,
+}
+
+static EMPTY_METADATA: Metadata = Metadata(Vec::new());
+
+impl<'a> CommentFormatterArgs<'a> {
+    pub(crate) fn new(docs: &'a Cow<'static, str>, deprecated: Option<&'a DeprecatedType>) -> Self {
+        Self {
+            docs,
+            deprecated,
+            metadata: &EMPTY_METADATA,
+            constraints: None,
+            #[cfg(feature = "serde_json")]
+            default_value: None,
+            #[cfg(feature = "serde_json")]
+            example: None,
+        }
+    }
+
+    pub(crate) fn field(field: &'a Field) -> Self {
+        Self {
+            metadata: field.metadata(),
+            constraints: Some(field.constraints()),
+            #[cfg(feature = "serde_json")]
+            default_value: field.default_value(),
+            #[cfg(feature = "serde_json")]
+            example: field.example(),
+            ..Self::new(field.docs(), field.deprecated())
+        }
+    }
+
+    pub(crate) fn named(ty: &'a NamedDataType) -> Self {
+        Self {
+            metadata: ty.metadata(),
+            #[cfg(feature = "serde_json")]
+            example: ty.example(),
+            ..Self::new(ty.docs(), ty.deprecated())
+        }
+    }
+
+    pub(crate) fn variant(variant: &'a EnumVariant) -> Self {
+        Self {
+            metadata: variant.metadata(),
+            ..Self::new(variant.docs(), variant.deprecated())
+        }
+    }
 }
 
 /// The signature for a function responsible for exporting Typescript comments.
@@ -17,6 +80,39 @@ pub type CommentFormatterFn = fn(CommentFormatterArgs) -> String; // TODO: Retur
 /// The signature for a function responsible for formatter a Typescript file.
 pub type FormatterFn = fn(PathBuf) -> io::Result<()>;
 
+/// The function which is called with each [`ExportWarning`]. Set using [`ExportConfig::on_warning`].
+#[derive(Clone)]
+pub struct WarningHandler(Arc<dyn Fn(ExportWarning) + Send + Sync>);
+
+impl WarningHandler {
+    pub(crate) fn call(&self, warning: ExportWarning) {
+        (self.0)(warning)
+    }
+
+    /// Wrap the handler so it's only called once for each warning.
+    /// The same warning is found again when a type is exported for each [`SerdeMode`] or inlined multiple times.
+    pub(crate) fn deduplicated(&self) -> Self {
+        let handler = self.clone();
+        // The message contains the kind of warning and it's path.
+        let seen = Mutex::new(BTreeSet::new());
+        Self(Arc::new(move |warning| {
+            let is_new = seen
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(warning.to_string());
+            if is_new {
+                handler.call(warning);
+            }
+        }))
+    }
+}
+
+impl fmt::Debug for WarningHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WarningHandler")
+    }
+}
+
 /// Options for controlling the behavior of the Typescript exporter.
 #[derive(Debug, Clone)]
 pub struct ExportConfig {
@@ -26,10 +122,32 @@ pub struct ExportConfig {
     pub(crate) comment_exporter: Option<CommentFormatterFn>,
     /// How the resulting file should be formatted.
     pub(crate) formatter: Option<FormatterFn>,
+    /// Whether object fields, lists and maps should be exported as immutable.
+    /// This can be overridden on a type or field basis by using `#[specta(readonly = false)]`.
+    pub(crate) readonly: bool,
+    /// The maximum length of a fixed-length array (`[T; N]`) which will be exported as a tuple. `0` (the default) disables this.
+    pub(crate) tuple_array_max_length: usize,
+    /// Which direction of a Serde round trip to export the types for. `None` merges both directions.
+    pub(crate) serde_mode: Option<SerdeMode>,
+    /// Whether to suffix type names with `Input` or `Output` depending on the [`SerdeMode`].
+    pub(crate) serde_mode_suffix: bool,
+    /// Whether to keep exporting after an error so every problem can be reported at once.
+    pub(crate) collect_errors: bool,
+    /// Called with each warning found while exporting.
+    pub(crate) warning_handler: Option<WarningHandler>,
     /// Whether to export types by default.
     /// This can be overridden on a type basis by using `#[specta(export)]`.
     #[cfg(feature = "export")]
     pub(crate) export_by_default: Option<bool>,
+    /// Which types to export.
+    #[cfg(feature = "export")]
+    pub(crate) filter: crate::export::ExportFilter,
+    /// The order the types are written in.
+    #[cfg(feature = "export")]
+    pub(crate) order: crate::export::ExportOrder,
+    /// The comment at the top of the exported file.
+    #[cfg(feature = "export")]
+    pub(crate) header: Cow<'static, str>,
 }
 
 impl ExportConfig {
@@ -68,6 +186,76 @@ impl ExportConfig {
         self
     }
 
+    /// Configure whether object fields are marked `readonly`, lists are exported as `readonly T[]` and maps are wrapped in `Readonly<...>`.
+    ///
+    /// This can be overridden on a specific type or field by using `#[specta(readonly = false)]`.
+    pub fn readonly(mut self, readonly: bool) -> Self {
+        self.readonly = readonly;
+        self
+    }
+
+    /// Configure the maximum length of a fixed-length array (`[T; N]`) which will be exported as a Typescript tuple. Eg. `[number, number, number]` for `[f32; 3]`.
+    ///
+    /// Arrays longer than this are exported as `T[]`.
+    ///
+    /// This is off by default (`0`) so every fixed-length array is exported as `T[]` unless a maximum length is configured.
+    pub fn tuple_array_max_length(mut self, max_length: usize) -> Self {
+        self.tuple_array_max_length = max_length;
+        self
+    }
+
+    /// Configure which direction of a Serde round trip the types should be exported for.
+    ///
+    /// [`SerdeMode::Deserialize`] will export the shape your API accepts (eg. `#[serde(default)]` fields become optional) and [`SerdeMode::Serialize`] the shape your API returns (eg. `#[serde(skip_serializing_if = "...")]` fields become optional).
+    /// `None` (the default) will export a single type which is compatible with both directions.
+    pub fn serde_mode(mut self, mode: Option<SerdeMode>) -> Self {
+        self.serde_mode = mode;
+        self
+    }
+
+    /// Configure whether type names should be suffixed with `Input` ([`SerdeMode::Deserialize`]) or `Output` ([`SerdeMode::Serialize`]).
+    ///
+    /// This has no effect unless a [`SerdeMode`] is set, except for [`export::ts_with_cfg`](crate::export::ts_with_cfg) which will export both the `Input` and `Output` variant of every type when no [`SerdeMode`] is set.
+    pub fn serde_mode_suffix(mut self, suffix: bool) -> Self {
+        self.serde_mode_suffix = suffix;
+        self
+    }
+
+    /// Configure whether the exporter should keep going after an error and report every problem it finds.
+    ///
+    /// When enabled all the errors are returned together as [`ExportError::Multiple`](super::ExportError::Multiple) instead of failing on the first one.
+    pub fn collect_errors(mut self, collect: bool) -> Self {
+        self.collect_errors = collect;
+        self
+    }
+
+    /// Configure a function which is called with every [`ExportWarning`] found while exporting.
+    ///
+    /// Warnings don't cause the export to fail. To fail on them (Eg. in CI) collect them and check afterwards:
+    ///
+    /// ```rust
+    /// use std::sync::{Arc, Mutex};
+    /// use specta::{ts::{self, ExportConfig}, Any, Type};
+    ///
+    /// #[derive(Type)]
+    /// pub struct Event {
+    ///     pub payload: Any,
+    /// }
+    ///
+    /// let warnings = Arc::new(Mutex::new(Vec::new()));
+    /// let conf = ExportConfig::default().on_warning({
+    ///     let warnings = warnings.clone();
+    ///     move |warning| warnings.lock().unwrap().push(warning)
+    /// });
+    ///
+    /// ts::export::<Event>(&conf).unwrap();
+    /// assert_eq!(warnings.lock().unwrap().len(), 1);
+    /// ```
+    pub fn on_warning(mut self, handler: impl Fn(ExportWarning) + Send + Sync + 'static) -> Self {
+        self.warning_handler = Some(WarningHandler(Arc::new(handler)));
+        self
+    }
+
     /// Configure whether or not to export types by default.
     ///
     /// This can be overridden on a specific type by using `#[specta(export)]`.
@@ -79,6 +267,46 @@ impl ExportConfig {
         self
     }
 
+    /// Configure which types should be exported.
+    ///
+    /// When the [`ExportFilter`](crate::export::ExportFilter) has roots only they and their dependencies are exported, ignoring [`ExportConfig::export_by_default`] and `#[specta(export = false)]`.
+    ///
+    /// This parameter only takes effect when this configuration if passed into [`export::ts_with_cfg`](crate::export::ts_with_cfg)
+    #[cfg(feature = "export")]
+    pub fn filter(mut self, filter: crate::export::ExportFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Configure the order the types are written in. Defaults to [`ExportOrder::Sid`](crate::export::ExportOrder::Sid).
+    ///
+    /// This parameter only takes effect when this configuration if passed into [`export::ts_with_cfg`](crate::export::ts_with_cfg)
+    #[cfg(feature = "export")]
+    pub fn order(mut self, order: crate::export::ExportOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Configure the comment at the top of the exported file. An empty string will disable the header.
+    ///
+    /// Defaults to `// This file has been generated by Specta. DO NOT EDIT.`
+    ///
+    /// This parameter only takes effect when this configuration if passed into [`export::ts_with_cfg`](crate::export::ts_with_cfg) or the other functions in [`export`](crate::export).
+    #[cfg(feature = "export")]
+    pub fn header(mut self, header: impl Into<Cow<'static, str>>) -> Self {
+        self.header = header.into();
+        self
+    }
+
+    /// The suffix which should be appended to the name of named types.
+    pub(crate) fn type_name_suffix(&self) -> &'static str {
+        match (self.serde_mode_suffix, self.serde_mode) {
+            (true, Some(SerdeMode::Deserialize)) => "Input",
+            (true, Some(SerdeMode::Serialize)) => "Output",
+            _ => "",
+        }
+    }
+
     /// Run the specified formatter on the given path.
     pub fn run_format(&self, path: PathBuf) -> io::Result<()> {
         if let Some(formatter) = self.formatter {
@@ -86,6 +314,17 @@ impl ExportConfig {
         }
         Ok(())
     }
+
+    /// A copy of the config where each warning is only reported once. Use this for everything which is exported as part of a single run.
+    pub(crate) fn with_deduplicated_warnings(&self) -> Cow<'_, Self> {
+        match &self.warning_handler {
+            Some(handler) => Cow::Owned(Self {
+                warning_handler: Some(handler.deduplicated()),
+                ..self.clone()
+            }),
+            None => Cow::Borrowed(self),
+        }
+    }
 }
 
 impl Default for ExportConfig {
@@ -94,8 +333,20 @@ impl Default for ExportConfig {
             bigint: Default::default(),
             comment_exporter: Some(comments::js_doc),
             formatter: None,
+            readonly: false,
+            tuple_array_max_length: 0,
+            serde_mode: None,
+            serde_mode_suffix: false,
+            collect_errors: false,
+            warning_handler: None,
             #[cfg(feature = "export")]
             export_by_default: None,
+            #[cfg(feature = "export")]
+            filter: Default::default(),
+            #[cfg(feature = "export")]
+            order: Default::default(),
+            #[cfg(feature = "export")]
+            header: Cow::Borrowed("// This file has been generated by Specta. DO NOT EDIT."),
         }
     }
 }
@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, cell::RefCell, collections::BTreeSet};
 
 pub mod comments;
 mod context;
@@ -7,13 +7,17 @@ mod export_config;
 mod formatter;
 mod reserved_terms;
 
-pub use context::*;
+pub use super::ExportPath;
+pub(crate) use context::*;
 pub use error::*;
 pub use export_config::*;
 pub use formatter::*;
 use reserved_terms::*;
 
-use crate::*;
+use crate::{
+    datatype::visit::{walk_datatype, Visitor},
+    *,
+};
 
 #[allow(missing_docs)]
 pub type Result<T> = std::result::Result<T, ExportError>;
@@ -36,14 +40,9 @@ pub fn export<T: NamedType>(conf: &ExportConfig) -> Output {
         parent_inline: false,
         type_map: &mut type_map,
     });
-    is_valid_ty(&named_data_type.inner, &type_map)?;
     let result = export_named_datatype(conf, &named_data_type, &type_map);
 
-    if let Some((ty_name, l0, l1)) = detect_duplicate_type_names(&type_map).into_iter().next() {
-        return Err(ExportError::DuplicateTypeName(ty_name, l0, l1));
-    }
-
-    result
+    check_duplicate_type_names(conf, &type_map, result)
 }
 
 /// Convert a type which implements [`Type`](crate::Type) to a TypeScript string.
@@ -65,14 +64,20 @@ pub fn inline<T: Type>(conf: &ExportConfig) -> Output {
         },
         &[],
     );
-    is_valid_ty(&ty, &type_map)?;
-    let result = datatype(conf, &ty, &type_map);
 
-    if let Some((ty_name, l0, l1)) = detect_duplicate_type_names(&type_map).into_iter().next() {
-        return Err(ExportError::DuplicateTypeName(ty_name, l0, l1));
-    }
+    let conf = &*conf.with_deduplicated_warnings();
+    let errors = Default::default();
+    let ctx = ExportContext {
+        cfg: conf,
+        path: vec![],
+        is_export: false,
+        readonly: conf.readonly,
+        errors: &errors,
+    };
+    let result =
+        validate_ty(&ctx, &ty, &type_map).and_then(|_| datatype_inner(ctx, &ty, &type_map));
 
-    result
+    check_duplicate_type_names(conf, &type_map, with_collected_errors(conf, errors, result))
 }
 
 /// Convert a DataType to a TypeScript string
@@ -85,22 +90,74 @@ pub fn export_named_datatype(
 ) -> Output {
     // TODO: Duplicate type name detection?
 
-    is_valid_ty(&typ.inner, type_map)?;
-    export_datatype_inner(
-        ExportContext {
-            cfg: conf,
-            path: vec![],
-            is_export: true,
-        },
-        typ,
-        type_map,
-    )
+    let conf = &*conf.with_deduplicated_warnings();
+    let errors = Default::default();
+    let ctx = ExportContext {
+        cfg: conf,
+        path: vec![],
+        is_export: true,
+        readonly: conf.readonly,
+        errors: &errors,
+    };
+    let result = validate_ty(&ctx, &typ.inner, type_map)
+        .and_then(|_| export_datatype_inner(ctx, typ, type_map));
+
+    with_collected_errors(conf, errors, result)
+}
+
+/// Check the type is valid for Serde.
+fn validate_ty(ctx: &ExportContext, ty: &DataType, type_map: &TypeMap) -> Result<()> {
+    match is_valid_ty(ty, type_map) {
+        Ok(()) => Ok(()),
+        Err(errors) => errors.into_iter().try_for_each(|err| ctx.error(err.into())),
+    }
+}
+
+/// Return the errors recorded by [`ExportContext::error`] (along with the error the export failed with) when [`ExportConfig::collect_errors`] is enabled.
+pub(crate) fn with_collected_errors<T>(
+    conf: &ExportConfig,
+    errors: RefCell<Vec<ExportError>>,
+    result: Result<T>,
+) -> Result<T> {
+    if !conf.collect_errors {
+        return result;
+    }
+
+    let mut errors = errors.into_inner();
+    match result {
+        Ok(v) if errors.is_empty() => Ok(v),
+        Ok(_) => Err(ExportError::Multiple(errors)),
+        Err(err) => {
+            errors.extend(err.into_errors());
+            Err(ExportError::Multiple(errors))
+        }
+    }
+}
+
+/// Fail if the `type_map` contains multiple types with the same name.
+fn check_duplicate_type_names(conf: &ExportConfig, type_map: &TypeMap, result: Output) -> Output {
+    let mut duplicates = detect_duplicate_type_names(type_map)
+        .into_iter()
+        .map(|(ty_name, l0, l1)| ExportError::DuplicateTypeName(ty_name, l0, l1))
+        .collect::<Vec<_>>();
+
+    if duplicates.is_empty() {
+        result
+    } else if conf.collect_errors {
+        let mut errors = result
+            .err()
+            .map(ExportError::into_errors)
+            .unwrap_or_default();
+        errors.append(&mut duplicates);
+        Err(ExportError::Multiple(errors))
+    } else {
+        Err(duplicates.remove(0))
+    }
 }
 
 fn inner_comments(
     ctx: ExportContext,
-    deprecated: Option<&DeprecatedType>,
-    docs: &Cow<'static, str>,
+    args: CommentFormatterArgs,
     other: String,
     start_with_newline: bool,
 ) -> String {
@@ -111,7 +168,7 @@ fn inner_comments(
     let comments = ctx
         .cfg
         .comment_exporter
-        .map(|v| v(CommentFormatterArgs { docs, deprecated }))
+        .map(|v| v(args))
         .unwrap_or_default();
 
     let prefix = match start_with_newline && !comments.is_empty() {
@@ -125,16 +182,13 @@ fn inner_comments(
 fn export_datatype_inner(
     ctx: ExportContext,
     typ @ NamedDataType {
-        name,
-        docs,
-        deprecated,
-        inner: item,
-        ..
+        name, inner: item, ..
     }: &NamedDataType,
     type_map: &TypeMap,
 ) -> Output {
     let ctx = ctx.with(PathItem::Type(name.clone()));
     let name = sanitise_type_name(ctx.clone(), NamedLocation::Type, name)?;
+    let suffix = ctx.cfg.type_name_suffix();
 
     let generics = item
         .generics()
@@ -142,37 +196,67 @@ fn export_datatype_inner(
         .map(|generics| format!("<{}>", generics.join(", ")))
         .unwrap_or_default();
 
+    if let (Some(_), Some(generics)) = (&ctx.cfg.warning_handler, item.generics()) {
+        let mut used = UsedGenerics::default();
+        used.visit_datatype(item);
+
+        for generic in generics.iter().filter(|g| !used.0.contains(&g.0)) {
+            ctx.warn(|path| ExportWarning::UnusedGeneric(path, generic.0.clone()));
+        }
+    }
+
     let inline_ts = datatype_inner(ctx.clone(), &typ.inner, type_map)?;
 
     Ok(inner_comments(
         ctx,
-        deprecated.as_ref(),
-        docs,
-        format!("export type {name}{generics} = {inline_ts}"),
+        CommentFormatterArgs::named(typ),
+        format!("export type {name}{suffix}{generics} = {inline_ts}"),
         false,
     ))
 }
 
+/// Collects the names of the generics used in a type.
+#[derive(Default)]
+struct UsedGenerics(BTreeSet<Cow<'static, str>>);
+
+impl Visitor for UsedGenerics {
+    fn visit_datatype(&mut self, ty: &DataType) {
+        if let DataType::Generic(generic) = ty {
+            self.0.insert(generic.0.clone());
+        }
+        walk_datatype(self, ty)
+    }
+}
+
 /// Convert a DataType to a TypeScript string
 ///
 /// Eg. `{ demo: string; }`
 pub fn datatype(conf: &ExportConfig, typ: &DataType, type_map: &TypeMap) -> Output {
     // TODO: Duplicate type name detection?
 
-    datatype_inner(
+    let conf = &*conf.with_deduplicated_warnings();
+    let errors = Default::default();
+    let result = datatype_inner(
         ExportContext {
             cfg: conf,
             path: vec![],
             is_export: false,
+            readonly: conf.readonly,
+            errors: &errors,
         },
         typ,
         type_map,
-    )
+    );
+
+    with_collected_errors(conf, errors, result)
 }
 
 pub(crate) fn datatype_inner(ctx: ExportContext, typ: &DataType, type_map: &TypeMap) -> Output {
     Ok(match &typ {
-        DataType::Any => ANY.into(),
+        DataType::Any => {
+            ctx.warn(ExportWarning::Any);
+            ANY.into()
+        }
         DataType::Primitive(p) => {
             let ctx = ctx.with(PathItem::Type(p.to_rust_str().into()));
             match p {
@@ -182,18 +266,24 @@ pub(crate) fn datatype_inner(ctx: ExportContext, typ: &DataType, type_map: &Type
                     BigIntExportBehavior::Number => NUMBER.into(),
                     BigIntExportBehavior::BigInt => BIGINT.into(),
                     BigIntExportBehavior::Fail => {
-                        return Err(ExportError::BigIntForbidden(ctx.export_path()))
+                        ctx.error(ExportError::BigIntForbidden(ctx.export_path()))?;
+                        NUMBER.into()
                     }
                     BigIntExportBehavior::FailWithReason(reason) => {
-                        return Err(ExportError::Other(ctx.export_path(), reason.to_owned()))
+                        ctx.error(ExportError::Other(ctx.export_path(), reason.to_owned()))?;
+                        NUMBER.into()
                     }
                 },
-                primitive_def!(String char) => STRING.into(),
+                primitive_def!(String char) | PrimitiveType::FormattedString(_) => STRING.into(),
                 primitive_def!(bool) => BOOLEAN.into(),
             }
         }
         DataType::Literal(literal) => literal.to_ts(),
         DataType::Nullable(def) => {
+            if let DataType::Nullable(_) = **def {
+                ctx.warn(ExportWarning::NestedNullable);
+            }
+
             let dt = datatype_inner(ctx, def, type_map)?;
 
             if dt.ends_with(&format!(" | {NULL}")) {
@@ -203,20 +293,31 @@ pub(crate) fn datatype_inner(ctx: ExportContext, typ: &DataType, type_map: &Type
             }
         }
         DataType::Map(def) => {
-            format!(
+            let readonly = ctx.readonly;
+            let dt = format!(
                 // We use this isn't of `Record<K, V>` to avoid issues with circular references.
                 "{{ [key in {}]: {} }}",
                 datatype_inner(ctx.clone(), &def.0, type_map)?,
                 datatype_inner(ctx, &def.1, type_map)?
-            )
+            );
+
+            match readonly {
+                true => format!("Readonly<{dt}>"),
+                false => dt,
+            }
         }
         // We use `T[]` instead of `Array<T>` to avoid issues with circular references.
         DataType::List(def) => {
-            let dt = datatype_inner(ctx, def, type_map)?;
-            if dt.contains(' ') && !dt.ends_with('}') {
-                format!("({dt})[]")
-            } else {
-                format!("{dt}[]")
+            let prefix = readonly_prefix(ctx.readonly);
+            let max_length = ctx.cfg.tuple_array_max_length;
+            let dt = datatype_inner(ctx, def.ty(), type_map)?;
+            match def.length() {
+                // Small fixed-length arrays are exported as tuples so their length is checked.
+                Some(length) if max_length > 0 && length <= max_length => {
+                    format!("{prefix}[{}]", vec![dt; length].join(", "))
+                }
+                _ if dt.contains(' ') && !dt.ends_with('}') => format!("{prefix}({dt})[]"),
+                _ => format!("{prefix}{dt}[]"),
             }
         }
         DataType::Struct(item) => struct_datatype(
@@ -231,16 +332,24 @@ pub(crate) fn datatype_inner(ctx: ExportContext, typ: &DataType, type_map: &Type
             type_map,
         )?,
         DataType::Tuple(tuple) => tuple_datatype(ctx, tuple, type_map)?,
+        // The serialized shape is what's returned to the user so it's used when merging both directions.
+        DataType::Conversion(ty) => match ctx.cfg.serde_mode {
+            Some(SerdeMode::Deserialize) => datatype_inner(ctx, &ty.1, type_map)?,
+            Some(SerdeMode::Serialize) | None => datatype_inner(ctx, &ty.0, type_map)?,
+        },
         DataType::Result(result) => {
             let mut variants = vec![
                 datatype_inner(ctx.clone(), &result.0, type_map)?,
-                datatype_inner(ctx, &result.1, type_map)?,
+                datatype_inner(ctx.clone(), &result.1, type_map)?,
             ];
+            if variants[0] == variants[1] {
+                ctx.warn(ExportWarning::ResultCollapsed);
+            }
             variants.dedup();
             variants.join(" | ")
         }
         DataType::Reference(DataTypeReference { name, generics, .. }) => match &generics[..] {
-            [] => name.to_string(),
+            [] => format!("{name}{}", ctx.cfg.type_name_suffix()),
             generics => {
                 let generics = generics
                     .iter()
@@ -248,7 +357,7 @@ pub(crate) fn datatype_inner(ctx: ExportContext, typ: &DataType, type_map: &Type
                     .collect::<Result<Vec<_>>>()?
                     .join(", ");
 
-                format!("{name}<{generics}>")
+                format!("{name}{}<{generics}>", ctx.cfg.type_name_suffix())
             }
         },
         DataType::Generic(GenericType(ident)) => ident.to_string(),
@@ -260,20 +369,19 @@ fn unnamed_fields_datatype(ctx: ExportContext, fields: &[Field], type_map: &Type
     match fields {
         [field] => Ok(inner_comments(
             ctx.clone(),
-            field.deprecated(),
-            field.docs(),
-            datatype_inner(ctx, &field.ty, type_map)?,
+            CommentFormatterArgs::field(field),
+            datatype_inner(ctx.with_readonly(field.readonly), &field.ty, type_map)?,
             true,
         )),
         fields => Ok(format!(
-            "[{}]",
+            "{}[{}]",
+            readonly_prefix(ctx.readonly),
             fields
                 .iter()
                 .map(|field| Ok(inner_comments(
                     ctx.clone(),
-                    field.deprecated(),
-                    field.docs(),
-                    datatype_inner(ctx.clone(), &field.ty, type_map)?,
+                    CommentFormatterArgs::field(field),
+                    datatype_inner(ctx.with_readonly(field.readonly), &field.ty, type_map)?,
                     true
                 )))
                 .collect::<Result<Vec<_>>>()?
@@ -286,7 +394,8 @@ fn tuple_datatype(ctx: ExportContext, tuple: &TupleType, type_map: &TypeMap) ->
     match &tuple.fields[..] {
         [] => Ok(NULL.to_string()),
         tys => Ok(format!(
-            "[{}]",
+            "{}[{}]",
+            readonly_prefix(ctx.readonly),
             tys.iter()
                 .map(|v| datatype_inner(ctx.clone(), v, type_map))
                 .collect::<Result<Vec<_>>>()?
@@ -296,14 +405,15 @@ fn tuple_datatype(ctx: ExportContext, tuple: &TupleType, type_map: &TypeMap) ->
 }
 
 fn struct_datatype(ctx: ExportContext, key: &str, s: &StructType, type_map: &TypeMap) -> Output {
+    let ctx = ctx.with_readonly(s.readonly);
     match &s.fields {
         StructFields::Unit => Ok(NULL.into()),
         StructFields::Unnamed(s) => unnamed_fields_datatype(
-            ctx,
+            ctx.clone(),
             &s.fields
                 .iter()
                 .cloned()
-                .filter(|field| !field.skip)
+                .filter(|field| !ctx.skip_field(field))
                 .collect::<Vec<_>>()[..],
             type_map,
         ),
@@ -312,7 +422,7 @@ fn struct_datatype(ctx: ExportContext, key: &str, s: &StructType, type_map: &Typ
                 .fields
                 .iter()
                 .cloned()
-                .filter(|(_, field)| !field.skip)
+                .filter(|(_, field)| !ctx.skip_field(field))
                 .collect::<Vec<_>>();
 
             if fields.is_empty() {
@@ -325,17 +435,20 @@ fn struct_datatype(ctx: ExportContext, key: &str, s: &StructType, type_map: &Typ
             let mut field_sections = flattened
                 .into_iter()
                 .map(|(key, field)| {
-                    datatype_inner(ctx.with(PathItem::Field(key.clone())), &field.ty, type_map).map(
-                        |type_str| {
-                            inner_comments(
-                                ctx.clone(),
-                                field.deprecated(),
-                                field.docs(),
-                                format!("({type_str})"),
-                                true,
-                            )
-                        },
+                    datatype_inner(
+                        ctx.with(PathItem::Field(key.clone()))
+                            .with_readonly(field.readonly),
+                        &field.ty,
+                        type_map,
                     )
+                    .map(|type_str| {
+                        inner_comments(
+                            ctx.clone(),
+                            CommentFormatterArgs::field(field),
+                            format!("({type_str})"),
+                            true,
+                        )
+                    })
                 })
                 .collect::<Result<Vec<_>>>()?;
 
@@ -344,8 +457,7 @@ fn struct_datatype(ctx: ExportContext, key: &str, s: &StructType, type_map: &Typ
                 .map(|(key, field)| {
                     Ok(inner_comments(
                         ctx.clone(),
-                        field.deprecated(),
-                        field.docs(),
+                        CommentFormatterArgs::field(field),
                         object_field_to_ts(
                             ctx.with(PathItem::Field(key.clone())),
                             key.clone(),
@@ -358,7 +470,8 @@ fn struct_datatype(ctx: ExportContext, key: &str, s: &StructType, type_map: &Typ
                 .collect::<Result<Vec<_>>>()?;
 
             if let Some(tag) = &s.tag {
-                unflattened_fields.push(format!("{tag}: \"{key}\""));
+                let prefix = readonly_prefix(ctx.readonly);
+                unflattened_fields.push(format!("{prefix}{tag}: \"{key}\""));
             }
 
             if !unflattened_fields.is_empty() {
@@ -381,8 +494,9 @@ fn enum_variant_datatype(
         EnumVariants::Unit => unreachable!("Unit enum variants have no type!"),
         EnumVariants::Named(obj) => {
             let mut fields = if let Some(tag) = &obj.tag {
+                let prefix = readonly_prefix(ctx.readonly);
                 let sanitised_name = sanitise_key(name, true);
-                vec![format!("{tag}: {sanitised_name}")]
+                vec![format!("{prefix}{tag}: {sanitised_name}")]
             } else {
                 vec![]
             };
@@ -390,12 +504,11 @@ fn enum_variant_datatype(
             fields.extend(
                 obj.fields
                     .iter()
-                    .filter(|(_, field)| !field.skip)
+                    .filter(|(_, field)| !ctx.skip_field(field))
                     .map(|(name, field)| {
                         Ok(inner_comments(
                             ctx.clone(),
-                            field.deprecated(),
-                            field.docs(),
+                            CommentFormatterArgs::field(field),
                             object_field_to_ts(
                                 ctx.with(PathItem::Field(name.clone())),
                                 name.clone(),
@@ -417,8 +530,8 @@ fn enum_variant_datatype(
             let fields = obj
                 .fields
                 .iter()
-                .filter(|field| !field.skip)
-                .map(|field| datatype_inner(ctx.clone(), &field.ty, type_map))
+                .filter(|field| !ctx.skip_field(field))
+                .map(|field| datatype_inner(ctx.with_readonly(field.readonly), &field.ty, type_map))
                 .collect::<Result<Vec<_>>>()?;
 
             Ok(match &fields[..] {
@@ -433,13 +546,18 @@ fn enum_variant_datatype(
                 }
                 // If the actual length is 1, we know `#[serde(skip)]` was not used.
                 [field] if obj.fields.len() == 1 => Some(field.to_string()),
-                fields => Some(format!("[{}]", fields.join(", "))),
+                fields => Some(format!(
+                    "{}[{}]",
+                    readonly_prefix(ctx.readonly),
+                    fields.join(", ")
+                )),
             })
         }
     }
 }
 
 fn enum_datatype(ctx: ExportContext, e: &EnumType, type_map: &TypeMap) -> Output {
+    let ctx = ctx.with_readonly(e.readonly);
     if e.variants().is_empty() {
         return Ok(NEVER.to_string());
     }
@@ -449,14 +567,13 @@ fn enum_datatype(ctx: ExportContext, e: &EnumType, type_map: &TypeMap) -> Output
             let mut variants = e
                 .variants
                 .iter()
-                .filter(|(_, variant)| !variant.skip)
+                .filter(|(_, variant)| !ctx.skip_variant(variant))
                 .map(|(name, variant)| {
                     Ok(match variant.inner {
                         EnumVariants::Unit => NULL.to_string(),
                         _ => inner_comments(
                             ctx.clone(),
-                            variant.deprecated(),
-                            variant.docs(),
+                            CommentFormatterArgs::variant(variant),
                             enum_variant_datatype(
                                 ctx.with(PathItem::Variant(name.clone())),
                                 type_map,
@@ -476,18 +593,24 @@ fn enum_datatype(ctx: ExportContext, e: &EnumType, type_map: &TypeMap) -> Output
             let mut variants = e
                 .variants
                 .iter()
-                .filter(|(_, variant)| !variant.skip)
+                .filter(|(_, variant)| !ctx.skip_variant(variant))
                 .map(|(variant_name, variant)| {
-                    let sanitised_name = sanitise_key(variant_name.clone(), true);
+                    let prefix = readonly_prefix(ctx.readonly);
+                    // A `#[serde(other)]` variant will be deserialized from any unknown tag.
+                    let sanitised_name = match variant.other()
+                        && ctx.cfg.serde_mode == Some(SerdeMode::Deserialize)
+                    {
+                        true => Cow::Borrowed("string"),
+                        false => sanitise_key(variant_name.clone(), true),
+                    };
 
                     Ok(inner_comments(
                         ctx.clone(),
-                        variant.deprecated(),
-                        variant.docs(),
+                        CommentFormatterArgs::variant(variant),
                         match (repr, &variant.inner) {
                             (EnumRepr::Untagged, _) => unreachable!(),
                             (EnumRepr::Internal { tag }, EnumVariants::Unit) => {
-                                format!("{{ {tag}: {sanitised_name} }}")
+                                format!("{{ {prefix}{tag}: {sanitised_name} }}")
                             }
                             (EnumRepr::Internal { tag }, EnumVariants::Unnamed(tuple)) => {
                                 let mut typ =
@@ -496,17 +619,17 @@ fn enum_datatype(ctx: ExportContext, e: &EnumType, type_map: &TypeMap) -> Output
                                 // TODO: This `null` check is a bad fix for an internally tagged type with a `null` variant being exported as `{ type: "A" } & null` (which is `never` in TS)
                                 // TODO: Move this check into the macros so it can apply to any language cause it should (it's just hard to do in the macros)
                                 if typ == "null" {
-                                    format!("({{ {tag}: {sanitised_name} }})")
+                                    format!("({{ {prefix}{tag}: {sanitised_name} }})")
                                 } else {
                                     // We wanna be sure `... & ... | ...` becomes `... & (... | ...)`
                                     if typ.contains('|') {
                                         typ = format!("({typ})");
                                     }
-                                    format!("({{ {tag}: {sanitised_name} }} & {typ})")
+                                    format!("({{ {prefix}{tag}: {sanitised_name} }} & {typ})")
                                 }
                             }
                             (EnumRepr::Internal { tag }, EnumVariants::Named(obj)) => {
-                                let mut fields = vec![format!("{tag}: {sanitised_name}")];
+                                let mut fields = vec![format!("{prefix}{tag}: {sanitised_name}")];
 
                                 fields.extend(
                                     obj.fields
@@ -536,13 +659,13 @@ fn enum_datatype(ctx: ExportContext, e: &EnumType, type_map: &TypeMap) -> Output
 
                                 match ts_values {
                                     Some(ts_values) => {
-                                        format!("{{ {sanitised_name}: {ts_values} }}")
+                                        format!("{{ {prefix}{sanitised_name}: {ts_values} }}")
                                     }
                                     None => format!(r#""{sanitised_name}""#),
                                 }
                             }
                             (EnumRepr::Adjacent { tag, .. }, EnumVariants::Unit) => {
-                                format!("{{ {tag}: {sanitised_name} }}")
+                                format!("{{ {prefix}{tag}: {sanitised_name} }}")
                             }
                             (EnumRepr::Adjacent { tag, content }, _) => {
                                 let ts_values = enum_variant_datatype(
@@ -553,7 +676,7 @@ fn enum_datatype(ctx: ExportContext, e: &EnumType, type_map: &TypeMap) -> Output
                                 )?
                                 .expect("Invalid Serde type");
 
-                                format!("{{ {tag}: {sanitised_name}; {content}: {ts_values} }}")
+                                format!("{{ {prefix}{tag}: {sanitised_name}; {prefix}{content}: {ts_values} }}")
                             }
                         },
                         true,
@@ -593,14 +716,26 @@ fn object_field_to_ts(
     type_map: &TypeMap,
 ) -> Output {
     let field_name_safe = sanitise_key(key, false);
+    let ctx = ctx.with_readonly(field.readonly);
+    let prefix = readonly_prefix(ctx.readonly);
 
     // https://github.com/oscartbeaumont/rspc/issues/100#issuecomment-1373092211
-    let (key, ty) = match field.optional {
+    let (key, ty) = match ctx.optional_field(field) {
         true => (format!("{field_name_safe}?").into(), &field.ty),
         false => (field_name_safe, &field.ty),
     };
 
-    Ok(format!("{key}: {}", datatype_inner(ctx, ty, type_map)?))
+    Ok(format!(
+        "{prefix}{key}: {}",
+        datatype_inner(ctx, ty, type_map)?
+    ))
+}
+
+fn readonly_prefix(readonly: bool) -> &'static str {
+    match readonly {
+        true => "readonly ",
+        false => "",
+    }
 }
 
 /// sanitise a string to be a valid Typescript key
@@ -623,16 +758,18 @@ fn sanitise_key<'a>(field_name: Cow<'static, str>, force_string: bool) -> Cow<'a
 
 pub(crate) fn sanitise_type_name(ctx: ExportContext, loc: NamedLocation, ident: &str) -> Output {
     if let Some(name) = RESERVED_TYPE_NAMES.iter().find(|v| **v == ident) {
-        return Err(ExportError::ForbiddenName(loc, ctx.export_path(), name));
+        ctx.error(ExportError::ForbiddenName(loc, ctx.export_path(), name))?;
+        return Ok(ident.to_string());
     }
 
     if let Some(first_char) = ident.chars().nth(0) {
         if !first_char.is_alphabetic() && first_char != '_' {
-            return Err(ExportError::InvalidName(
+            ctx.error(ExportError::InvalidName(
                 loc,
                 ctx.export_path(),
                 ident.to_string(),
-            ));
+            ))?;
+            return Ok(ident.to_string());
         }
     }
 
@@ -640,11 +777,11 @@ pub(crate) fn sanitise_type_name(ctx: ExportContext, loc: NamedLocation, ident:
         .find(|c: char| !c.is_alphanumeric() && c != '_')
         .is_some()
     {
-        return Err(ExportError::InvalidName(
+        ctx.error(ExportError::InvalidName(
             loc,
             ctx.export_path(),
             ident.to_string(),
-        ));
+        ))?;
     }
 
     Ok(ident.to_string())
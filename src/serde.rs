@@ -1,237 +1,468 @@
+use std::{borrow::Cow, collections::BTreeSet};
+
 use thiserror::Error;
 
 use crate::{
-    DataType, EnumRepr, EnumType, EnumVariants, LiteralType, PrimitiveType, StructFields, TypeMap,
+    DataType, DataTypeReference, EnumRepr, EnumType, EnumVariants, ExportPath, Field, LiteralType,
+    NamedDataType, PathItem, PrimitiveType, SpectaID, StructFields, TypeMap,
 };
 
-// TODO: The error should show a path to the type causing the issue like the BigInt error reporting.
-
 #[derive(Error, Debug, PartialEq)]
 pub enum SerdeError {
-    #[error("A map key must be a 'string' or 'number' type")]
-    InvalidMapKey,
-    #[error("#[specta(tag = \"...\")] cannot be used with tuple variants")]
-    InvalidInternallyTaggedEnum,
-    #[error("the usage of #[specta(skip)] means the type can't be serialized")]
-    InvalidUsageOfSkip,
+    #[error("Attempted to export '{0}' but a map key must be a 'string' or 'number' type")]
+    InvalidMapKey(ExportPath),
+    #[error(
+        "Attempted to export '{0}' but #[specta(tag = \"...\")] cannot be used with tuple variants"
+    )]
+    InvalidInternallyTaggedEnum(ExportPath),
+    #[error("Attempted to export '{0}' but the usage of #[specta(skip)] means the type can't be serialized")]
+    InvalidUsageOfSkip(ExportPath),
+    #[error(
+        "Attempted to export '{0}' but the referenced type '{1}' was not found in the type map"
+    )]
+    ReferenceNotFound(ExportPath, Cow<'static, str>),
+    #[error("Attempted to export '{0}' but #[serde(flatten)] can only be used on a struct or map")]
+    InvalidFlatten(ExportPath),
+    #[error("Attempted to export '{0}' but the field name '{1}' is used multiple times")]
+    DuplicateFieldName(ExportPath, Cow<'static, str>),
+    #[error("Attempted to export '{0}' but the tag '{1}' collides with a field of the same name")]
+    TagCollision(ExportPath, Cow<'static, str>),
+}
+
+/// The direction data is flowing through Serde.
+///
+/// Attributes like `#[serde(skip_serializing)]` or `#[serde(default)]` only apply to one direction so a type can have a different shape when it's being serialized (eg. a response) and when it's being deserialized (eg. a request body).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SerdeMode {
+    /// The type is being serialized. Eg. the output of your API.
+    Serialize,
+    /// The type is being deserialized. Eg. the input to your API.
+    Deserialize,
 }
 
 /// Check that a [DataType] is a valid for Serde.
 ///
 /// This can be used by exporters which wanna do export-time checks that all types are compatible with Serde formats.
-pub(crate) fn is_valid_ty(dt: &DataType, type_map: &TypeMap) -> Result<(), SerdeError> {
-    match dt {
-        DataType::Nullable(ty) => is_valid_ty(ty, type_map)?,
-        DataType::Map(ty) => {
-            is_valid_map_key(&ty.0, type_map)?;
-            is_valid_ty(&ty.1, type_map)?;
+/// Every problem found is returned, not just the first one, so they can all be reported at once.
+pub(crate) fn is_valid_ty(dt: &DataType, type_map: &TypeMap) -> Result<(), Vec<SerdeError>> {
+    let path = match dt {
+        DataType::Struct(ty) => vec![PathItem::Type(ty.name.clone())],
+        DataType::Enum(ty) => vec![PathItem::Type(ty.name.clone())],
+        _ => vec![],
+    };
+
+    let mut validator = Validator {
+        type_map,
+        path,
+        visited: Default::default(),
+        errors: Default::default(),
+    };
+    validator.ty(dt);
+
+    match validator.errors.is_empty() {
+        true => Ok(()),
+        false => Err(validator.errors),
+    }
+}
+
+struct Validator<'a> {
+    type_map: &'a TypeMap,
+    path: Vec<PathItem>,
+    // The named types which have already been checked. This also stops recursive types from looping forever.
+    visited: BTreeSet<SpectaID>,
+    errors: Vec<SerdeError>,
+}
+
+impl<'a> Validator<'a> {
+    fn error(&self, err: fn(ExportPath) -> SerdeError) -> SerdeError {
+        err(ExportPath::new(&self.path))
+    }
+
+    // Record the error and carry on so every problem with the type is found.
+    fn report(&mut self, result: Result<(), SerdeError>) {
+        if let Err(err) = result {
+            self.errors.push(err);
+        }
+    }
+
+    fn with<T>(&mut self, item: PathItem, func: impl FnOnce(&mut Self) -> T) -> T {
+        self.path.push(item);
+        let result = func(self);
+        self.path.pop();
+        result
+    }
+
+    fn resolve(&self, reference: &DataTypeReference) -> Result<&'a NamedDataType, SerdeError> {
+        match self.type_map.get(&reference.sid) {
+            Some(Some(ty)) => Ok(ty),
+            _ => Err(SerdeError::ReferenceNotFound(
+                ExportPath::new(&self.path),
+                reference.name.clone(),
+            )),
         }
-        DataType::Struct(ty) => match ty.fields() {
-            StructFields::Unit => {}
-            StructFields::Unnamed(ty) => {
-                for field in ty.fields().iter() {
-                    is_valid_ty(&field.ty, type_map)?;
+    }
+
+    fn ty(&mut self, dt: &DataType) {
+        match dt {
+            DataType::Nullable(ty) => self.ty(ty),
+            DataType::Map(ty) => {
+                let result = self.map_key(&ty.0);
+                self.report(result);
+                self.ty(&ty.1);
+            }
+            DataType::Struct(ty) => match ty.fields() {
+                StructFields::Unit => {}
+                StructFields::Unnamed(ty) => self.unnamed_fields(ty.fields()),
+                StructFields::Named(ty) => {
+                    let result = self.validate_keys(ty.fields(), ty.tag().as_ref());
+                    self.report(result);
+                    self.named_fields(ty.fields());
+                }
+            },
+            DataType::Enum(ty) => {
+                self.validate_enum(ty);
+
+                for (variant_name, variant) in ty.variants().iter() {
+                    self.with(
+                        PathItem::Variant(variant_name.clone()),
+                        |v| match &variant.inner {
+                            EnumVariants::Unit => {}
+                            EnumVariants::Named(variant) => {
+                                let result = v.validate_keys(&variant.fields, variant.tag.as_ref());
+                                v.report(result);
+                                v.named_fields(&variant.fields);
+                            }
+                            EnumVariants::Unnamed(variant) => v.unnamed_fields(&variant.fields),
+                        },
+                    );
                 }
             }
-            StructFields::Named(ty) => {
-                for (_field_name, field) in ty.fields().iter() {
-                    is_valid_ty(&field.ty, type_map)?;
+            DataType::Tuple(ty) => {
+                for field in ty.fields.iter() {
+                    self.ty(field);
                 }
             }
-        },
-        DataType::Enum(ty) => {
-            validate_enum(ty, type_map)?;
+            DataType::Result(ty) | DataType::Conversion(ty) => {
+                self.ty(&ty.0);
+                self.ty(&ty.1);
+            }
+            DataType::Reference(reference) => {
+                for generic in &reference.generics {
+                    self.ty(generic);
+                }
 
-            for (_variant_name, variant) in ty.variants().iter() {
-                match &variant.inner {
-                    EnumVariants::Unit => {}
-                    EnumVariants::Named(variant) => {
-                        for (_field_name, field) in variant.fields.iter() {
-                            is_valid_ty(&field.ty, type_map)?;
-                        }
-                    }
-                    EnumVariants::Unnamed(variant) => {
-                        for field in variant.fields.iter() {
-                            is_valid_ty(&field.ty, type_map)?;
-                        }
+                if self.visited.insert(reference.sid) {
+                    match self.resolve(reference) {
+                        Ok(ty) => self.with(PathItem::Type(ty.name.clone()), |v| v.ty(&ty.inner)),
+                        Err(err) => self.errors.push(err),
                     }
                 }
             }
+            _ => {}
         }
-        DataType::Tuple(ty) => {
-            for field in ty.fields.iter() {
-                is_valid_ty(field, type_map)?;
+    }
+
+    fn named_fields(&mut self, fields: &[(Cow<'static, str>, Field)]) {
+        for (field_name, field) in fields {
+            self.with(PathItem::Field(field_name.clone()), |v| {
+                if field.flatten {
+                    let result = v.validate_flatten(&field.ty);
+                    v.report(result);
+                }
+
+                v.ty(&field.ty)
+            });
+        }
+    }
+
+    // Serde can only flatten types which serialize as a map.
+    fn validate_flatten(&mut self, ty: &DataType) -> Result<(), SerdeError> {
+        match ty {
+            // We can't know what these will be so we give them the benefit of the doubt.
+            DataType::Any | DataType::Generic(_) => Ok(()),
+            DataType::Map(_) | DataType::Enum(_) => Ok(()),
+            DataType::Struct(ty) if !matches!(ty.fields, StructFields::Unnamed(_)) => Ok(()),
+            // Serde will omit all the fields when it's `None`
+            DataType::Nullable(ty) => self.validate_flatten(ty),
+            DataType::Conversion(ty) => {
+                self.validate_flatten(&ty.0)?;
+                self.validate_flatten(&ty.1)
+            }
+            DataType::Reference(reference) => {
+                let ty = self.resolve(reference)?;
+                self.with(PathItem::Type(ty.name.clone()), |v| {
+                    v.validate_flatten(&ty.inner)
+                })
             }
+            _ => Err(self.error(SerdeError::InvalidFlatten)),
         }
-        DataType::Result(ty) => {
-            is_valid_ty(&ty.0, type_map)?;
-            is_valid_ty(&ty.1, type_map)?;
+    }
+
+    // Serde will produce an object with duplicate keys if two fields end up with the same name (Eg. after a `rename` or `flatten`) or a field is named the same as the tag.
+    fn validate_keys(
+        &mut self,
+        fields: &[(Cow<'static, str>, Field)],
+        tag: Option<&Cow<'static, str>>,
+    ) -> Result<(), SerdeError> {
+        let mut keys = Vec::new();
+        self.field_keys(fields, &mut keys)?;
+
+        let mut seen = BTreeSet::new();
+        for key in &keys {
+            if tag == Some(key) {
+                return Err(SerdeError::TagCollision(
+                    ExportPath::new(&self.path),
+                    key.clone(),
+                ));
+            }
+
+            if !seen.insert(key) {
+                return Err(SerdeError::DuplicateFieldName(
+                    ExportPath::new(&self.path),
+                    key.clone(),
+                ));
+            }
         }
-        DataType::Reference(ty) => {
-            for generic in &ty.generics {
-                is_valid_ty(generic, type_map)?;
+
+        Ok(())
+    }
+
+    // The keys of the object the fields will be serialized into.
+    fn field_keys(
+        &mut self,
+        fields: &[(Cow<'static, str>, Field)],
+        keys: &mut Vec<Cow<'static, str>>,
+    ) -> Result<(), SerdeError> {
+        for (field_name, field) in fields {
+            if field.skip_serializing && field.skip_deserializing {
+                continue;
             }
 
-            let ty = type_map
-                .get(&ty.sid)
-                .as_ref()
-                .expect("Reference type not found")
-                .as_ref()
-                .expect("Type was never populated"); // TODO: Error properly
+            if field.flatten {
+                self.flattened_keys(&field.ty, keys)?;
+            } else {
+                keys.push(field_name.clone());
+            }
+        }
 
-            is_valid_ty(&ty.inner, type_map)?;
+        Ok(())
+    }
+
+    // The keys a type will contribute to the parent object when it's flattened or wrapped by an internally tagged variant.
+    // Only structs have a known set of keys, everything else is ignored.
+    fn flattened_keys(
+        &mut self,
+        ty: &DataType,
+        keys: &mut Vec<Cow<'static, str>>,
+    ) -> Result<(), SerdeError> {
+        match ty {
+            DataType::Struct(ty) => {
+                if let StructFields::Named(fields) = &ty.fields {
+                    keys.extend(fields.tag.clone());
+                    self.field_keys(&fields.fields, keys)?;
+                }
+            }
+            DataType::Nullable(ty) => self.flattened_keys(ty, keys)?,
+            DataType::Reference(reference) => {
+                let ty = self.resolve(reference)?;
+                self.flattened_keys(&ty.inner, keys)?;
+            }
+            _ => {}
         }
-        _ => {}
+
+        Ok(())
     }
 
-    Ok(())
-}
+    fn unnamed_fields(&mut self, fields: &[Field]) {
+        for (i, field) in fields.iter().enumerate() {
+            self.with(PathItem::Field(i.to_string().into()), |v| v.ty(&field.ty));
+        }
+    }
 
-// Typescript: Must be assignable to `string | number | symbol` says Typescript.
-fn is_valid_map_key(key_ty: &DataType, type_map: &TypeMap) -> Result<(), SerdeError> {
-    match key_ty {
-        DataType::Any => Ok(()),
-        DataType::Primitive(ty) => match ty {
-            PrimitiveType::i8
-            | PrimitiveType::i16
-            | PrimitiveType::i32
-            | PrimitiveType::i64
-            | PrimitiveType::i128
-            | PrimitiveType::isize
-            | PrimitiveType::u8
-            | PrimitiveType::u16
-            | PrimitiveType::u32
-            | PrimitiveType::u64
-            | PrimitiveType::u128
-            | PrimitiveType::usize
-            | PrimitiveType::f32
-            | PrimitiveType::f64
-            | PrimitiveType::String
-            | PrimitiveType::char => Ok(()),
-            _ => Err(SerdeError::InvalidMapKey),
-        },
-        DataType::Literal(ty) => match ty {
-            LiteralType::i8(_)
-            | LiteralType::i16(_)
-            | LiteralType::i32(_)
-            | LiteralType::u8(_)
-            | LiteralType::u16(_)
-            | LiteralType::u32(_)
-            | LiteralType::f32(_)
-            | LiteralType::f64(_)
-            | LiteralType::String(_)
-            | LiteralType::char(_) => Ok(()),
-            _ => Err(SerdeError::InvalidMapKey),
-        },
-        // Enum of other valid types are also valid Eg. `"A" | "B"` or `"A" | 5` are valid
-        DataType::Enum(ty) => {
-            for (_variant_name, variant) in &ty.variants {
-                match &variant.inner {
-                    EnumVariants::Unit => {}
-                    EnumVariants::Unnamed(item) => {
-                        if item.fields.len() > 1 {
-                            return Err(SerdeError::InvalidMapKey);
-                        }
+    // Typescript: Must be assignable to `string | number | symbol` says Typescript.
+    fn map_key(&mut self, key_ty: &DataType) -> Result<(), SerdeError> {
+        match key_ty {
+            DataType::Any => Ok(()),
+            DataType::Primitive(ty) => match ty {
+                PrimitiveType::i8
+                | PrimitiveType::i16
+                | PrimitiveType::i32
+                | PrimitiveType::i64
+                | PrimitiveType::i128
+                | PrimitiveType::isize
+                | PrimitiveType::u8
+                | PrimitiveType::u16
+                | PrimitiveType::u32
+                | PrimitiveType::u64
+                | PrimitiveType::u128
+                | PrimitiveType::usize
+                | PrimitiveType::f32
+                | PrimitiveType::f64
+                | PrimitiveType::String
+                | PrimitiveType::FormattedString(_)
+                | PrimitiveType::char => Ok(()),
+                _ => Err(self.error(SerdeError::InvalidMapKey)),
+            },
+            DataType::Literal(ty) => match ty {
+                LiteralType::i8(_)
+                | LiteralType::i16(_)
+                | LiteralType::i32(_)
+                | LiteralType::u8(_)
+                | LiteralType::u16(_)
+                | LiteralType::u32(_)
+                | LiteralType::f32(_)
+                | LiteralType::f64(_)
+                | LiteralType::String(_)
+                | LiteralType::char(_) => Ok(()),
+                _ => Err(self.error(SerdeError::InvalidMapKey)),
+            },
+            // Enum of other valid types are also valid Eg. `"A" | "B"` or `"A" | 5` are valid
+            DataType::Enum(ty) => {
+                for (_variant_name, variant) in &ty.variants {
+                    match &variant.inner {
+                        EnumVariants::Unit => {}
+                        EnumVariants::Unnamed(item) => {
+                            if item.fields.len() > 1 {
+                                return Err(self.error(SerdeError::InvalidMapKey));
+                            }
 
-                        if ty.repr != EnumRepr::Untagged {
-                            return Err(SerdeError::InvalidMapKey);
+                            if ty.repr != EnumRepr::Untagged {
+                                return Err(self.error(SerdeError::InvalidMapKey));
+                            }
                         }
+                        _ => return Err(self.error(SerdeError::InvalidMapKey)),
                     }
-                    _ => return Err(SerdeError::InvalidMapKey),
                 }
-            }
 
-            Ok(())
-        }
-        DataType::Reference(ty) => {
-            let ty = type_map
-                .get(&ty.sid)
-                .as_ref()
-                .expect("Reference type not found")
-                .as_ref()
-                .expect("Type was never populated"); // TODO: Error properly
-
-            is_valid_map_key(&ty.inner, type_map)
+                Ok(())
+            }
+            DataType::Reference(reference) => {
+                let ty = self.resolve(reference)?;
+                self.with(PathItem::Type(ty.name.clone()), |v| v.map_key(&ty.inner))
+            }
+            DataType::Conversion(ty) => {
+                self.map_key(&ty.0)?;
+                self.map_key(&ty.1)
+            }
+            _ => Err(self.error(SerdeError::InvalidMapKey)),
         }
-        _ => Err(SerdeError::InvalidMapKey),
     }
-}
 
-// Serde does not allow serializing a variant of certain types of enum's.
-fn validate_enum(e: &EnumType, type_map: &TypeMap) -> Result<(), SerdeError> {
-    // You can't `#[serde(skip)]` your way to an empty enum.
-    let valid_variants = e.variants().iter().filter(|(_, v)| !v.skip).count();
-    if valid_variants == 0 && e.variants().len() != 0 {
-        return Err(SerdeError::InvalidUsageOfSkip);
-    }
+    // Serde does not allow serializing a variant of certain types of enum's.
+    fn validate_enum(&mut self, e: &EnumType) {
+        // You can't `#[serde(skip)]` your way to an empty enum.
+        let valid_variants = e.variants().iter().filter(|(_, v)| !v.skip()).count();
+        if valid_variants == 0 && e.variants().len() != 0 {
+            let err = self.error(SerdeError::InvalidUsageOfSkip);
+            self.errors.push(err);
+            return;
+        }
 
-    // Only internally tagged enums can be invalid.
-    if let EnumRepr::Internal { .. } = e.repr() {
-        validate_internally_tag_enum(e, type_map)?;
-    }
+        match e.repr() {
+            // Only internally tagged enums can be invalid.
+            EnumRepr::Internal { tag } => {
+                let result = self.validate_internally_tag_enum(e);
+                self.report(result);
 
-    Ok(())
-}
+                for (variant_name, variant) in &e.variants {
+                    let result = self.with(PathItem::Variant(variant_name.clone()), |v| {
+                        let mut keys = Vec::new();
+                        match &variant.inner {
+                            EnumVariants::Unit => {}
+                            EnumVariants::Named(fields) => {
+                                v.field_keys(&fields.fields, &mut keys)?
+                            }
+                            EnumVariants::Unnamed(fields) => {
+                                for field in &fields.fields {
+                                    v.flattened_keys(&field.ty, &mut keys)?;
+                                }
+                            }
+                        }
 
-// Checks for specially internally tagged enums.
-fn validate_internally_tag_enum(e: &EnumType, type_map: &TypeMap) -> Result<(), SerdeError> {
-    for (_variant_name, variant) in &e.variants {
-        match &variant.inner {
-            EnumVariants::Unit => {}
-            EnumVariants::Named(_) => {}
-            EnumVariants::Unnamed(item) => {
-                let fields = item.fields();
-                if fields.len() > 1 {
-                    return Err(SerdeError::InvalidInternallyTaggedEnum);
+                        match keys.contains(tag) {
+                            true => Err(SerdeError::TagCollision(
+                                ExportPath::new(&v.path),
+                                tag.clone(),
+                            )),
+                            false => Ok(()),
+                        }
+                    });
+                    self.report(result);
                 }
-
-                validate_internally_tag_enum_datatype(&fields[0].ty, type_map)?;
             }
+            EnumRepr::Adjacent { tag, content } if tag == content => {
+                self.errors.push(SerdeError::TagCollision(
+                    ExportPath::new(&self.path),
+                    tag.clone(),
+                ));
+            }
+            _ => {}
         }
     }
 
-    Ok(())
-}
+    // Checks for specially internally tagged enums.
+    fn validate_internally_tag_enum(&mut self, e: &EnumType) -> Result<(), SerdeError> {
+        for (variant_name, variant) in &e.variants {
+            match &variant.inner {
+                EnumVariants::Unit => {}
+                EnumVariants::Named(_) => {}
+                EnumVariants::Unnamed(item) => {
+                    self.with(PathItem::Variant(variant_name.clone()), |v| {
+                        let fields = item.fields();
+                        if fields.len() > 1 {
+                            return Err(v.error(SerdeError::InvalidInternallyTaggedEnum));
+                        }
 
-// Internally tagged enums require map-type's (with a couple of exceptions like `null`)
-// Which makes sense when you can't represent `{ "type": "A" } & string` in a single JSON value.
-fn validate_internally_tag_enum_datatype(
-    ty: &DataType,
-    type_map: &TypeMap,
-) -> Result<(), SerdeError> {
-    match ty {
-        // `serde_json::Any` can be *technically* be either valid or invalid based on the actual data but we are being strict and reject it.
-        DataType::Any => return Err(SerdeError::InvalidInternallyTaggedEnum),
-        DataType::Map(_) => {}
-        // Structs's are always map-types unless they are transparent then it depends on inner type. However, transparent passes through when calling `Type::inline` so we don't need to specially check that case.
-        DataType::Struct(_) => {}
-        DataType::Enum(ty) => match ty.repr {
-            // Is only valid if the enum itself is also valid.
-            EnumRepr::Untagged => validate_internally_tag_enum(ty, type_map)?,
-            // Eg. `{ "Variant": "value" }` is a map-type so valid.
-            EnumRepr::External => {}
-            // Eg. `{ "type": "variant", "field": "value" }` is a map-type so valid.
-            EnumRepr::Internal { .. } => {}
-            // Eg. `{ "type": "variant", "c": {} }` is a map-type so valid.
-            EnumRepr::Adjacent { .. } => {}
-        },
-        // `()` is `null` and is valid
-        DataType::Tuple(ty) if ty.fields.is_empty() => {}
-        // Are valid as they are serialized as an map-type. Eg. `"Ok": 5` or `"Error": "todo"`
-        DataType::Result(_) => {}
-        // References need to be checked against the same rules.
-        DataType::Reference(ty) => {
-            let ty = type_map
-                .get(&ty.sid)
-                .as_ref()
-                .expect("Reference type not found")
-                .as_ref()
-                .expect("Type was never populated"); // TODO: Error properly
-
-            validate_internally_tag_enum_datatype(&ty.inner, type_map)?;
+                        v.validate_internally_tag_enum_datatype(&fields[0].ty)
+                    })?;
+                }
+            }
         }
-        _ => return Err(SerdeError::InvalidInternallyTaggedEnum),
+
+        Ok(())
     }
 
-    Ok(())
+    // Internally tagged enums require map-type's (with a couple of exceptions like `null`)
+    // Which makes sense when you can't represent `{ "type": "A" } & string` in a single JSON value.
+    fn validate_internally_tag_enum_datatype(&mut self, ty: &DataType) -> Result<(), SerdeError> {
+        match ty {
+            // `serde_json::Any` can be *technically* be either valid or invalid based on the actual data but we are being strict and reject it.
+            DataType::Any => return Err(self.error(SerdeError::InvalidInternallyTaggedEnum)),
+            DataType::Map(_) => {}
+            // Structs's are always map-types unless they are transparent then it depends on inner type. However, transparent passes through when calling `Type::inline` so we don't need to specially check that case.
+            // Tuple structs are serialized as a sequence (or as their inner value for newtype structs) so are invalid.
+            DataType::Struct(ty) if matches!(ty.fields, StructFields::Unnamed(_)) => {
+                return Err(self.error(SerdeError::InvalidInternallyTaggedEnum))
+            }
+            DataType::Struct(_) => {}
+            DataType::Enum(ty) => match ty.repr {
+                // Is only valid if the enum itself is also valid.
+                EnumRepr::Untagged => self.validate_internally_tag_enum(ty)?,
+                // Eg. `{ "Variant": "value" }` is a map-type so valid.
+                EnumRepr::External => {}
+                // Eg. `{ "type": "variant", "field": "value" }` is a map-type so valid.
+                EnumRepr::Internal { .. } => {}
+                // Eg. `{ "type": "variant", "c": {} }` is a map-type so valid.
+                EnumRepr::Adjacent { .. } => {}
+            },
+            // `()` is `null` and is valid
+            DataType::Tuple(ty) if ty.fields.is_empty() => {}
+            // Are valid as they are serialized as an map-type. Eg. `"Ok": 5` or `"Error": "todo"`
+            DataType::Result(_) => {}
+            DataType::Conversion(ty) => {
+                self.validate_internally_tag_enum_datatype(&ty.0)?;
+                self.validate_internally_tag_enum_datatype(&ty.1)?;
+            }
+            // References need to be checked against the same rules.
+            DataType::Reference(reference) => {
+                let ty = self.resolve(reference)?;
+                self.with(PathItem::Type(ty.name.clone()), |v| {
+                    v.validate_internally_tag_enum_datatype(&ty.inner)
+                })?;
+            }
+            _ => return Err(self.error(SerdeError::InvalidInternallyTaggedEnum)),
+        }
+
+        Ok(())
+    }
 }
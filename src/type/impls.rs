@@ -1,7 +1,5 @@
 use crate::{reference::Reference, *};
 
-use std::borrow::Cow;
-
 impl_primitives!(
     i8 i16 i32 i64 i128 isize
     u8 u16 u32 u64 u128 usize
@@ -63,11 +61,12 @@ impl_as!(
 );
 
 use std::net::*;
+impl_as_string_format!(
+    IpAddr as Ip
+    Ipv4Addr as Ipv4
+    Ipv6Addr as Ipv6
+);
 impl_as!(
-    IpAddr as String
-    Ipv4Addr as String
-    Ipv6Addr as String
-
     SocketAddr as String
     SocketAddrV4 as String
     SocketAddrV6 as String
@@ -122,7 +121,15 @@ impl<'a, T: Type> Type for &'a [T] {
 
 impl<const N: usize, T: Type> Type for [T; N] {
     fn inline(opts: DefOpts, generics: &[DataType]) -> DataType {
-        <Vec<T>>::inline(opts, generics)
+        DataType::List(ListType {
+            ty: Box::new(
+                generics
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(|| T::inline(opts, generics)),
+            ),
+            length: Some(N),
+        })
     }
 }
 
@@ -175,31 +182,13 @@ impl<T: Type> Type for std::ops::Range<T> {
         let ty = T::definition(opts);
         DataType::Struct(StructType {
             name: "Range".into(),
+            deny_unknown_fields: false,
+            readonly: None,
             generics: vec![],
             fields: StructFields::Named(NamedFields {
                 fields: vec![
-                    (
-                        "start".into(),
-                        Field {
-                            skip: false,
-                            optional: false,
-                            flatten: false,
-                            deprecated: None,
-                            docs: Cow::Borrowed(""),
-                            ty: ty.clone(),
-                        },
-                    ),
-                    (
-                        "end".into(),
-                        Field {
-                            skip: false,
-                            optional: false,
-                            flatten: false,
-                            deprecated: None,
-                            docs: Cow::Borrowed(""),
-                            ty,
-                        },
-                    ),
+                    ("start".into(), Field::new(ty.clone())),
+                    ("end".into(), Field::new(ty)),
                 ],
                 tag: None,
             }),
@@ -257,60 +246,26 @@ const _: () = {
             DataType::Enum(EnumType {
                 name: "Number".into(),
                 repr: EnumRepr::Untagged,
+                deny_unknown_fields: false,
+                readonly: None,
                 variants: vec![
                     (
                         "f64".into(),
-                        EnumVariant {
-                            skip: false,
-                            docs: Cow::Borrowed(""),
-                            deprecated: None,
-                            inner: EnumVariants::Unnamed(UnnamedFields {
-                                fields: vec![Field {
-                                    skip: false,
-                                    optional: false,
-                                    flatten: false,
-                                    deprecated: None,
-                                    docs: Cow::Borrowed(""),
-                                    ty: DataType::Primitive(PrimitiveType::f64),
-                                }],
-                            }),
-                        },
+                        EnumVariant::new(EnumVariants::Unnamed(UnnamedFields {
+                            fields: vec![Field::new(DataType::Primitive(PrimitiveType::f64))],
+                        })),
                     ),
                     (
                         "i64".into(),
-                        EnumVariant {
-                            skip: false,
-                            docs: Cow::Borrowed(""),
-                            deprecated: None,
-                            inner: EnumVariants::Unnamed(UnnamedFields {
-                                fields: vec![Field {
-                                    skip: false,
-                                    optional: false,
-                                    flatten: false,
-                                    deprecated: None,
-                                    docs: Cow::Borrowed(""),
-                                    ty: DataType::Primitive(PrimitiveType::i64),
-                                }],
-                            }),
-                        },
+                        EnumVariant::new(EnumVariants::Unnamed(UnnamedFields {
+                            fields: vec![Field::new(DataType::Primitive(PrimitiveType::i64))],
+                        })),
                     ),
                     (
                         "u64".into(),
-                        EnumVariant {
-                            skip: false,
-                            docs: Cow::Borrowed(""),
-                            deprecated: None,
-                            inner: EnumVariants::Unnamed(UnnamedFields {
-                                fields: vec![Field {
-                                    skip: false,
-                                    optional: false,
-                                    flatten: false,
-                                    deprecated: None,
-                                    docs: Cow::Borrowed(""),
-                                    ty: DataType::Primitive(PrimitiveType::u64),
-                                }],
-                            }),
-                        },
+                        EnumVariant::new(EnumVariants::Unnamed(UnnamedFields {
+                            fields: vec![Field::new(DataType::Primitive(PrimitiveType::u64))],
+                        })),
                     ),
                 ],
                 generics: vec![],
@@ -344,60 +299,26 @@ const _: () = {
             DataType::Enum(EnumType {
                 name: "Number".into(),
                 repr: EnumRepr::Untagged,
+                deny_unknown_fields: false,
+                readonly: None,
                 variants: vec![
                     (
                         "f64".into(),
-                        EnumVariant {
-                            skip: false,
-                            docs: Cow::Borrowed(""),
-                            deprecated: None,
-                            inner: EnumVariants::Unnamed(UnnamedFields {
-                                fields: vec![Field {
-                                    skip: false,
-                                    optional: false,
-                                    flatten: false,
-                                    deprecated: None,
-                                    docs: Cow::Borrowed(""),
-                                    ty: DataType::Primitive(PrimitiveType::f64),
-                                }],
-                            }),
-                        },
+                        EnumVariant::new(EnumVariants::Unnamed(UnnamedFields {
+                            fields: vec![Field::new(DataType::Primitive(PrimitiveType::f64))],
+                        })),
                     ),
                     (
                         "i64".into(),
-                        EnumVariant {
-                            skip: false,
-                            docs: Cow::Borrowed(""),
-                            deprecated: None,
-                            inner: EnumVariants::Unnamed(UnnamedFields {
-                                fields: vec![Field {
-                                    skip: false,
-                                    optional: false,
-                                    flatten: false,
-                                    deprecated: None,
-                                    docs: Cow::Borrowed(""),
-                                    ty: DataType::Primitive(PrimitiveType::i64),
-                                }],
-                            }),
-                        },
+                        EnumVariant::new(EnumVariants::Unnamed(UnnamedFields {
+                            fields: vec![Field::new(DataType::Primitive(PrimitiveType::i64))],
+                        })),
                     ),
                     (
                         "u64".into(),
-                        EnumVariant {
-                            skip: false,
-                            docs: Cow::Borrowed(""),
-                            deprecated: None,
-                            inner: EnumVariants::Unnamed(UnnamedFields {
-                                fields: vec![Field {
-                                    skip: false,
-                                    optional: false,
-                                    flatten: false,
-                                    deprecated: None,
-                                    docs: Cow::Borrowed(""),
-                                    ty: DataType::Primitive(PrimitiveType::u64),
-                                }],
-                            }),
-                        },
+                        EnumVariant::new(EnumVariants::Unnamed(UnnamedFields {
+                            fields: vec![Field::new(DataType::Primitive(PrimitiveType::u64))],
+                        })),
                     ),
                 ],
                 generics: vec![],
@@ -455,60 +376,61 @@ const _: () = {
 };
 
 #[cfg(feature = "uuid")]
-impl_as!(
-    uuid::Uuid as String
-    uuid::fmt::Hyphenated as String
+impl_as_string_format!(
+    uuid::Uuid as Uuid
+    uuid::fmt::Hyphenated as Uuid
 );
 
 #[cfg(feature = "chrono")]
 const _: () = {
     use chrono::*;
 
-    impl_as!(
-        NaiveDateTime as String
-        NaiveDate as String
-        NaiveTime as String
-        chrono::Duration as String
+    impl_as_string_format!(
+        NaiveDateTime as NaiveDateTime
+        NaiveDate as Date
+        NaiveTime as Time
     );
 
+    impl_as!(chrono::Duration as String);
+
     impl<T: TimeZone> Type for DateTime<T> {
-        fn inline(opts: DefOpts, generics: &[DataType]) -> DataType {
-            String::inline(opts, generics)
+        fn inline(_: DefOpts, _: &[DataType]) -> DataType {
+            StringFormat::DateTime.into()
         }
     }
 
     #[allow(deprecated)]
     impl<T: TimeZone> Type for Date<T> {
-        fn inline(opts: DefOpts, generics: &[DataType]) -> DataType {
-            String::inline(opts, generics)
+        fn inline(_: DefOpts, _: &[DataType]) -> DataType {
+            StringFormat::Date.into()
         }
     }
 };
 
 #[cfg(feature = "time")]
-impl_as!(
-    time::PrimitiveDateTime as String
-    time::OffsetDateTime as String
-    time::Date as String
-    time::Time as String
+impl_as_string_format!(
+    time::PrimitiveDateTime as NaiveDateTime
+    time::OffsetDateTime as DateTime
+    time::Date as Date
+    time::Time as Time
 );
 
 #[cfg(feature = "bigdecimal")]
-impl_as!(bigdecimal::BigDecimal as String);
+impl_as_string_format!(bigdecimal::BigDecimal as Decimal);
 
 // This assumes the `serde-with-str` feature is enabled. Check #26 for more info.
 #[cfg(feature = "rust_decimal")]
-impl_as!(rust_decimal::Decimal as String);
+impl_as_string_format!(rust_decimal::Decimal as Decimal);
 
 #[cfg(feature = "ipnetwork")]
-impl_as!(
-    ipnetwork::IpNetwork as String
-    ipnetwork::Ipv4Network as String
-    ipnetwork::Ipv6Network as String
+impl_as_string_format!(
+    ipnetwork::IpNetwork as IpNetwork
+    ipnetwork::Ipv4Network as IpNetwork
+    ipnetwork::Ipv6Network as IpNetwork
 );
 
 #[cfg(feature = "mac_address")]
-impl_as!(mac_address::MacAddress as String);
+impl_as_string_format!(mac_address::MacAddress as MacAddress);
 
 #[cfg(feature = "chrono")]
 impl_as!(
@@ -587,7 +509,7 @@ const _: () = {
 };
 
 #[cfg(feature = "url")]
-impl_as!(url::Url as String);
+impl_as_string_format!(url::Url as Uri);
 
 #[cfg(feature = "either")]
 impl<L: Type, R: Type> Type for either::Either<L, R> {
@@ -595,54 +517,32 @@ impl<L: Type, R: Type> Type for either::Either<L, R> {
         DataType::Enum(EnumType {
             name: "Either".into(),
             repr: EnumRepr::Untagged,
+            deny_unknown_fields: false,
+            readonly: None,
             variants: vec![
                 (
                     "Left".into(),
-                    EnumVariant {
-                        skip: false,
-                        docs: Cow::Borrowed(""),
-                        deprecated: None,
-                        inner: EnumVariants::Unnamed(UnnamedFields {
-                            fields: vec![Field {
-                                skip: false,
-                                optional: false,
-                                flatten: false,
-                                deprecated: None,
-                                docs: Cow::Borrowed(""),
-                                ty: L::inline(
-                                    DefOpts {
-                                        parent_inline: opts.parent_inline,
-                                        type_map: opts.type_map,
-                                    },
-                                    generics,
-                                ),
-                            }],
-                        }),
-                    },
+                    EnumVariant::new(EnumVariants::Unnamed(UnnamedFields {
+                        fields: vec![Field::new(L::inline(
+                            DefOpts {
+                                parent_inline: opts.parent_inline,
+                                type_map: opts.type_map,
+                            },
+                            generics,
+                        ))],
+                    })),
                 ),
                 (
                     "Right".into(),
-                    EnumVariant {
-                        skip: false,
-                        docs: Cow::Borrowed(""),
-                        deprecated: None,
-                        inner: EnumVariants::Unnamed(UnnamedFields {
-                            fields: vec![Field {
-                                skip: false,
-                                optional: false,
-                                flatten: false,
-                                deprecated: None,
-                                docs: Cow::Borrowed(""),
-                                ty: R::inline(
-                                    DefOpts {
-                                        parent_inline: opts.parent_inline,
-                                        type_map: opts.type_map,
-                                    },
-                                    generics,
-                                ),
-                            }],
-                        }),
-                    },
+                    EnumVariant::new(EnumVariants::Unnamed(UnnamedFields {
+                        fields: vec![Field::new(R::inline(
+                            DefOpts {
+                                parent_inline: opts.parent_inline,
+                                type_map: opts.type_map,
+                            },
+                            generics,
+                        ))],
+                    })),
                 ),
             ],
             generics: vec![],
@@ -96,11 +96,21 @@ macro_rules! impl_as {
     )+};
 }
 
+macro_rules! impl_as_string_format {
+    ($($ty:path as $format:ident)+) => {$(
+        impl Type for $ty {
+            fn inline(_: DefOpts, _: &[DataType]) -> DataType {
+                DataType::Primitive(PrimitiveType::FormattedString(StringFormat::$format))
+            }
+        }
+    )+};
+}
+
 macro_rules! impl_for_list {
     ($($ty:path as $name:expr)+) => {$(
         impl<T: Type> Type for $ty {
             fn inline(opts: DefOpts, generics: &[DataType]) -> DataType {
-                DataType::List(Box::new(generics.get(0).cloned().unwrap_or_else(|| T::inline(
+                DataType::List(ListType::new(generics.get(0).cloned().unwrap_or_else(|| T::inline(
                     opts,
                     generics,
                 ))))
@@ -108,7 +118,7 @@ macro_rules! impl_for_list {
 
             fn reference(opts: DefOpts, generics: &[DataType]) -> Reference {
                 Reference {
-                    inner: DataType::List(Box::new(generics.get(0).cloned().unwrap_or_else(
+                    inner: DataType::List(ListType::new(generics.get(0).cloned().unwrap_or_else(
                         || T::reference(opts, generics).inner,
                     ))),
                     _priv: (),
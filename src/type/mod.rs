@@ -106,6 +106,9 @@ pub mod reference {
                 name: "placeholder".into(),
                 docs: Cow::Borrowed(""),
                 deprecated: None,
+                #[cfg(feature = "serde_json")]
+                example: None,
+                metadata: Default::default(),
                 ext: None,
                 inner: DataType::Any,
             }));
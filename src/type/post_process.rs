@@ -1,6 +1,6 @@
 use std::{borrow::Cow, collections::HashMap};
 
-use crate::{ImplLocation, TypeMap};
+use crate::{ImplLocation, StableID, TypeMap};
 
 /// post process the type map to detect duplicate type names
 #[doc(hidden)]
@@ -33,3 +33,32 @@ pub fn detect_duplicate_type_names(
 
     errors
 }
+
+/// Find every [`StableID`] which is shared by multiple types in the `type_map`.
+///
+/// Returns the colliding ID along with the location of both types.
+pub fn detect_stable_id_collisions(
+    type_map: &TypeMap,
+) -> Vec<(StableID, ImplLocation, ImplLocation)> {
+    let mut errors = Vec::new();
+
+    let mut map = HashMap::with_capacity(type_map.len());
+    for (sid, ext) in type_map
+        .iter()
+        .filter_map(|(sid, dt)| Some((sid, dt.as_ref()?.ext()?)))
+    {
+        if let Some((existing_sid, existing_impl_location)) =
+            map.insert(&ext.stable_id, (sid, ext.impl_location))
+        {
+            if existing_sid != sid {
+                errors.push((
+                    ext.stable_id.clone(),
+                    ext.impl_location,
+                    existing_impl_location,
+                ));
+            }
+        }
+    }
+
+    errors
+}
@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use std::{borrow::Cow, cmp::Ordering, fmt};
 
 /// The unique Specta ID for the type.
 ///
@@ -16,6 +16,19 @@ pub struct SpectaID {
     pub(crate) hash: u64,
 }
 
+impl SpectaID {
+    /// Create an ID for a type which doesn't come from a Rust type. Eg. one built with [`StructType::builder`](crate::StructType::builder).
+    ///
+    /// The `type_identifier` must be unique for every type with the same `type_name`.
+    pub const fn new(type_name: &'static str, type_identifier: &'static str) -> Self {
+        crate::internal::construct::sid(type_name, type_identifier)
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
 // We do custom impls so the order prefers type_name over hash.
 impl Ord for SpectaID {
     fn cmp(&self, other: &Self) -> Ordering {
@@ -53,3 +66,108 @@ impl ImplLocation {
         self.0
     }
 }
+
+/// An identifier for a type which is stable between builds and crate versions.
+///
+/// It's made up of the module path and Rust name of the type. Eg. `my_crate::api::User`.
+/// Unlike [`SpectaID`] it doesn't depend on where in the file the type is defined so it's suitable as a persistent key (Eg. in schema snapshots), however it will change if the type is renamed or moved to another module.
+///
+/// Types defined inside a function share the module path of the function so two of them with the same name will have the same `StableID`.
+/// Use [`detect_stable_id_collisions`](crate::detect_stable_id_collisions) to check for this.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "schema",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct StableID(pub(crate) Cow<'static, str>);
+
+impl StableID {
+    /// Create an ID from it's string form. Eg. to find a type in a [`TypeMap`](crate::TypeMap) loaded from a previous build.
+    pub fn new(id: impl Into<Cow<'static, str>>) -> Self {
+        Self(id.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for StableID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// The hash is serialized as a hex string so it survives formats (like JSON in JS) which can't represent a full `u64`.
+#[cfg(feature = "schema")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedSpectaID<'a> {
+    #[serde(borrow)]
+    type_name: std::borrow::Cow<'a, str>,
+    hash: std::borrow::Cow<'a, str>,
+}
+
+#[cfg(feature = "schema")]
+impl serde::Serialize for SpectaID {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedSpectaID {
+            type_name: self.type_name.into(),
+            hash: format!("{:016x}", self.hash).into(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl<'de> serde::Deserialize<'de> for SpectaID {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = SerializedSpectaID::deserialize(deserializer)?;
+
+        Ok(Self {
+            type_name: intern(&id.type_name),
+            hash: u64::from_str_radix(&id.hash, 16).map_err(serde::de::Error::custom)?,
+        })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl serde::Serialize for ImplLocation {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl<'de> serde::Deserialize<'de> for ImplLocation {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let location = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Ok(Self(intern(&location)))
+    }
+}
+
+/// `SpectaID` and `ImplLocation` hold `&'static str`'s as they normally come from the macros.
+/// When deserializing we leak each distinct string once so loading the same schema repeatedly doesn't keep growing memory.
+#[cfg(feature = "schema")]
+fn intern(s: &str) -> &'static str {
+    use std::{
+        collections::BTreeSet,
+        sync::{Mutex, OnceLock, PoisonError},
+    };
+
+    static STRINGS: OnceLock<Mutex<BTreeSet<&'static str>>> = OnceLock::new();
+
+    let mut strings = STRINGS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    match strings.get(s) {
+        Some(s) => s,
+        None => {
+            let s: &'static str = Box::leak(s.to_owned().into_boxed_str());
+            strings.insert(s);
+            s
+        }
+    }
+}
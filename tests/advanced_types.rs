@@ -2,7 +2,7 @@
 
 use std::collections::HashMap;
 
-use specta::Type;
+use specta::{ts::ExportPath, SerdeError, Type};
 
 use crate::ts::assert_ts;
 
@@ -88,6 +88,18 @@ struct A {
     e: GenericFlattened<u32>,
 }
 
+#[derive(Type)]
+#[specta(export = false)]
+struct FlattenedWithoutDuplicates {
+    a: B,
+    #[specta(inline)]
+    b: B,
+    #[specta(inline, flatten)]
+    d: D,
+    #[specta(inline, flatten)]
+    e: GenericFlattened<u32>,
+}
+
 #[derive(Type)]
 #[specta(export = false)]
 struct ToBeFlattened {
@@ -140,11 +152,21 @@ struct BoxInline {
 
 #[test]
 fn test_inlining() {
+    // `b` is both a field and a field of the flattened `c`
     assert_ts!(
+        error;
         A,
-        "({ b: number }) & ({ flattened: number }) & ({ generic_flattened: number }) & { a: B; b: { b: number } }"
+        SerdeError::DuplicateFieldName(ExportPath::new_unsafe("A"), "b".into())
+    );
+    assert_ts!(
+        FlattenedWithoutDuplicates,
+        "({ flattened: number }) & ({ generic_flattened: number }) & { a: B; b: { b: number } }"
+    );
+    assert_ts!(
+        error;
+        DoubleFlattened,
+        SerdeError::DuplicateFieldName(ExportPath::new_unsafe("DoubleFlattened"), "a".into())
     );
-    assert_ts!(DoubleFlattened, "({ a: string }) & ({ a: string })");
 
     // TODO: All of these currently fail.
     // assert_ts!(FlattenedInner, ""); // TODO: This is wrong
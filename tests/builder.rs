@@ -0,0 +1,158 @@
+use std::borrow::Cow;
+
+use specta::{
+    ts::{export_named_datatype, ExportConfig},
+    DataType, DataTypeReference, EnumRepr, EnumType, EnumVariant, EnumVariants, Field, ListType,
+    NamedDataType, NamedFields, PrimitiveType, SpectaID, StableID, StructType, TupleType, TypeMap,
+    UnnamedFields,
+};
+
+fn export(ty: &NamedDataType) -> String {
+    export_named_datatype(&ExportConfig::default(), ty, &TypeMap::default()).expect("type is valid")
+}
+
+#[test]
+fn build_struct() {
+    let ty = StructType::builder("User")
+        .field("id", DataType::Primitive(PrimitiveType::i32))
+        .field(
+            "name",
+            Field::builder(PrimitiveType::String)
+                .optional()
+                .docs("The user's name"),
+        )
+        .field(
+            "tags",
+            DataType::from(ListType::new(DataType::Primitive(PrimitiveType::String))),
+        )
+        .field(
+            "position",
+            DataType::from(TupleType::new(vec![
+                DataType::Primitive(PrimitiveType::f64),
+                DataType::Primitive(PrimitiveType::f64),
+            ])),
+        )
+        .tag("type")
+        .build();
+
+    assert_eq!(
+        export(&NamedDataType::builder("User", ty).docs("A user").build()),
+        "/**\n * A user\n */\nexport type User = { id: number; \n/**\n * The user's name\n */\nname?: string; tags: string[]; position: [number, number]; type: \"User\" }"
+    );
+
+    let ty = StructType::builder("Point")
+        .unnamed_field(DataType::Primitive(PrimitiveType::i32))
+        .unnamed_field(DataType::Primitive(PrimitiveType::i32))
+        .build();
+    assert_eq!(
+        export(&ty.to_named("Point")),
+        "export type Point = [number, number]"
+    );
+
+    assert_eq!(
+        export(&StructType::builder("Unit").build().to_named("Unit")),
+        "export type Unit = null"
+    );
+}
+
+#[test]
+fn build_enum() {
+    let ty = EnumType::builder("Event", EnumRepr::External)
+        .variant("Started", EnumVariants::Unit)
+        .variant(
+            "Progress",
+            EnumVariant::builder(
+                UnnamedFields::builder().field(DataType::Primitive(PrimitiveType::u8)),
+            ),
+        )
+        .variant(
+            "Finished",
+            EnumVariant::builder(
+                NamedFields::builder().field("ok", DataType::Primitive(PrimitiveType::bool)),
+            )
+            .docs("The job finished"),
+        )
+        .variant("Hidden", EnumVariant::builder(EnumVariants::Unit).skip())
+        .build();
+
+    assert_eq!(
+        export(&ty.to_named("Event")),
+        "export type Event = \"Started\" | { Progress: number } | \n/**\n * The job finished\n */\n{ Finished: { ok: boolean } }"
+    );
+}
+
+#[test]
+fn build_generic() {
+    let ty = StructType::builder("Page")
+        .generic("T")
+        .field(
+            "items",
+            DataType::from(ListType::new(DataType::Generic(Cow::Borrowed("T").into()))),
+        )
+        .build();
+    assert_eq!(
+        export(&ty.to_named("Page")),
+        "export type Page<T> = { items: T[] }"
+    );
+
+    let reference = DataTypeReference::new(
+        "Page",
+        SpectaID::new("Page", "builder"),
+        vec![DataType::Primitive(PrimitiveType::String)],
+    );
+    assert_eq!(reference.generics().len(), 1);
+}
+
+#[test]
+fn build_reference() {
+    let role_sid = SpectaID::new("Role", "builder");
+    let role = NamedDataType::builder(
+        "Role",
+        EnumType::builder("Role", EnumRepr::External)
+            .variant("Admin", EnumVariants::Unit)
+            .variant("Member", EnumVariants::Unit)
+            .build(),
+    )
+    .sid(role_sid)
+    .module_path("app::users")
+    .build();
+
+    let ext = role.ext().expect("the sid was set");
+    assert_eq!(*ext.sid(), role_sid);
+    assert_eq!(ext.stable_id().as_str(), "app::users::Role");
+    assert_eq!(ext.module_path(), "app::users");
+
+    let user = NamedDataType::builder(
+        "User",
+        StructType::builder("User")
+            .field(
+                "role",
+                DataType::Reference(DataTypeReference::new("Role", role_sid, vec![])),
+            )
+            .build(),
+    )
+    .sid(SpectaID::new("User", "builder"))
+    .stable_id(StableID::new("app::User"))
+    .build();
+    assert_eq!(user.ext().unwrap().stable_id().as_str(), "app::User");
+
+    let type_map = [role, user.clone()]
+        .into_iter()
+        .map(|ty| (*ty.ext().unwrap().sid(), Some(ty)))
+        .collect::<TypeMap>();
+
+    assert_eq!(
+        export_named_datatype(&ExportConfig::default(), &user, &type_map),
+        Ok("export type User = { role: Role }".into())
+    );
+    assert_eq!(
+        export_named_datatype(
+            &ExportConfig::default(),
+            &type_map[&role_sid].clone().unwrap(),
+            &type_map
+        ),
+        Ok("export type Role = \"Admin\" | \"Member\"".into())
+    );
+    // The reference can't be resolved without the type
+    assert!(export_named_datatype(&ExportConfig::default(), &user, &TypeMap::default()).is_err());
+}
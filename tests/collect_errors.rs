@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use specta::{
+    ts::{self, ExportConfig, ExportError, ExportPath, NamedLocation},
+    SerdeError, Type,
+};
+
+#[derive(Type)]
+#[specta(export = false, rename = "enum")]
+pub struct Problems {
+    a: i64,
+    b: u128,
+    c: HashMap<(i32, i32), String>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct Bad {
+    a: HashMap<(i32, i32), String>,
+    b: HashMap<Vec<i32>, String>,
+    c: i64,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct NoProblems {
+    a: i32,
+}
+
+#[test]
+fn collect_errors() {
+    assert_eq!(
+        ts::export::<Problems>(&ExportConfig::default()),
+        Err(ExportError::Serde(SerdeError::InvalidMapKey(
+            ExportPath::new_unsafe("enum.c")
+        )))
+    );
+
+    let err = ts::export::<Problems>(&ExportConfig::default().collect_errors(true)).unwrap_err();
+    assert_eq!(
+        err,
+        ExportError::Multiple(vec![
+            ExportError::Serde(SerdeError::InvalidMapKey(ExportPath::new_unsafe("enum.c"))),
+            ExportError::ForbiddenName(NamedLocation::Type, ExportPath::new_unsafe("enum"), "enum"),
+            ExportError::BigIntForbidden(ExportPath::new_unsafe("enum -> enum.a -> i64")),
+            ExportError::BigIntForbidden(ExportPath::new_unsafe("enum -> enum.b -> u128")),
+        ])
+    );
+    assert_eq!(err.into_errors().len(), 4);
+
+    assert_eq!(
+        ts::inline::<Problems>(&ExportConfig::default().collect_errors(true))
+            .unwrap_err()
+            .into_errors()
+            .len(),
+        3
+    );
+
+    assert_eq!(
+        ts::export::<NoProblems>(&ExportConfig::default().collect_errors(true)),
+        Ok("export type NoProblems = { a: number }".into())
+    );
+}
+
+#[test]
+fn collect_multiple_serde_errors() {
+    assert_eq!(
+        ts::export::<Bad>(&ExportConfig::default()),
+        Err(ExportError::Serde(SerdeError::InvalidMapKey(
+            ExportPath::new_unsafe("Bad.a")
+        )))
+    );
+
+    assert_eq!(
+        ts::export::<Bad>(&ExportConfig::default().collect_errors(true)),
+        Err(ExportError::Multiple(vec![
+            ExportError::Serde(SerdeError::InvalidMapKey(ExportPath::new_unsafe("Bad.a"))),
+            ExportError::Serde(SerdeError::InvalidMapKey(ExportPath::new_unsafe("Bad.b"))),
+            ExportError::BigIntForbidden(ExportPath::new_unsafe("Bad -> Bad.c -> i64")),
+        ]))
+    );
+}
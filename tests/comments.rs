@@ -54,3 +54,29 @@ fn comments() {
     assert_ts_export!(CommentedEnum, "/**\n * Some triple-slash comment\n * Some more triple-slash comment\n */\nexport type CommentedEnum = \n/**\n * Some triple-slash comment\n * Some more triple-slash comment\n */\n{ A: number } | \n/**\n * Some triple-slash comment\n * Some more triple-slash comment\n */\n{ B: { \n/**\n * Some triple-slash comment\n * Some more triple-slash comment\n */\na: number } }");
     assert_ts_export!(SingleLineComment, "/**\n * Some single-line comment\n */\nexport type SingleLineComment = \n/**\n * Some single-line comment\n */\n{ A: number } | \n/**\n * Some single-line comment\n */\n{ B: { \n/**\n * Some single-line comment\n */\na: number } }");
 }
+
+/// Some generic comment
+#[derive(Type)]
+#[specta(export = false)]
+pub struct CommentedGeneric<T> {
+    /// Some field comment
+    #[specta(min = 0)]
+    a: T,
+}
+
+#[cfg(feature = "js_doc")]
+#[test]
+fn js_doc_format_comment() {
+    use specta::{js_doc, ts::ExportConfig, NamedType};
+
+    let type_map = crate::ts::type_map::<CommentedGeneric<()>>();
+    let ty = type_map[&CommentedGeneric::<()>::SID].clone().unwrap();
+
+    assert_eq!(
+        js_doc::format_comment(&ExportConfig::default(), &ty, &type_map),
+        Ok(
+            "/**\n * Some generic comment\n * @template T\n * @typedef { { a: T } } CommentedGeneric\n */\n"
+                .into()
+        )
+    );
+}
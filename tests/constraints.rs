@@ -0,0 +1,56 @@
+use specta::{DataType, DefOpts, FieldConstraints, StructFields, Type};
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct Constraints {
+    #[specta(min = 0, max = 100)]
+    a: i32,
+    /// Some comment
+    #[specta(min = -1.5)]
+    b: f64,
+    #[specta(length(min = 1, max = 64), pattern = "^[a-z]+$")]
+    c: String,
+    #[specta(length(equal = 3))]
+    d: Vec<i32>,
+    e: i32,
+}
+
+fn constraints<T: Type>() -> Vec<FieldConstraints> {
+    let DataType::Struct(s) = T::inline(
+        DefOpts {
+            parent_inline: false,
+            type_map: &mut Default::default(),
+        },
+        &[],
+    ) else {
+        panic!("expected struct");
+    };
+    let StructFields::Named(fields) = s.fields() else {
+        panic!("expected named fields");
+    };
+
+    fields
+        .fields()
+        .iter()
+        .map(|(_, field)| field.constraints().clone())
+        .collect()
+}
+
+#[test]
+fn constraints_metadata() {
+    let c = constraints::<Constraints>();
+    assert_eq!((c[0].min(), c[0].max()), (Some(0.0), Some(100.0)));
+    assert_eq!((c[1].min(), c[1].max()), (Some(-1.5), None));
+    assert_eq!((c[2].min_length(), c[2].max_length()), (Some(1), Some(64)));
+    assert_eq!(c[2].pattern().map(|v| &**v), Some("^[a-z]+$"));
+    assert_eq!((c[3].min_length(), c[3].max_length()), (Some(3), Some(3)));
+    assert!(c[4].is_empty());
+}
+
+#[test]
+fn constraints_js_doc() {
+    assert_eq!(
+        specta::ts::export::<Constraints>(&Default::default()),
+        Ok("export type Constraints = { \n/**\n * @minimum 0\n * @maximum 100\n */\na: number; \n/**\n * Some comment\n * @minimum -1.5\n */\nb: number; \n/**\n * @minLength 1\n * @maxLength 64\n * @pattern ^[a-z]+$\n */\nc: string; \n/**\n * @minLength 3\n * @maxLength 3\n */\nd: number[]; e: number }".into())
+    );
+}
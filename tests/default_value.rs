@@ -0,0 +1,95 @@
+#![cfg(feature = "serde_json")]
+
+use serde::{Deserialize, Serialize};
+use specta::{DataType, DefOpts, StructFields, Type};
+
+#[derive(Default, Serialize, Deserialize, Type)]
+#[specta(export = false)]
+pub struct NoDefaultValue {
+    a: i32,
+}
+
+// Doesn't implement `Serialize` so the default value can't be captured.
+#[derive(Default, Deserialize, Type)]
+#[specta(export = false)]
+pub struct NotSerializable {
+    a: i32,
+}
+
+fn default_page_size() -> u32 {
+    50
+}
+
+#[derive(Deserialize, Type)]
+#[specta(export = false)]
+pub struct DefaultValues {
+    #[serde(default)]
+    a: i32,
+    #[serde(default = "default_page_size")]
+    b: u32,
+    #[serde(default)]
+    c: Vec<String>,
+    #[serde(default)]
+    d: NoDefaultValue,
+    #[serde(default)]
+    e: NotSerializable,
+    f: String,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+#[specta(export = false)]
+pub struct GenericDefaultValue<T> {
+    #[serde(default)]
+    a: T,
+}
+
+fn default_values<T: Type>() -> Vec<Option<serde_json::Value>> {
+    let DataType::Struct(s) = T::inline(
+        DefOpts {
+            parent_inline: false,
+            type_map: &mut Default::default(),
+        },
+        &[DataType::Any],
+    ) else {
+        panic!("expected struct");
+    };
+    let StructFields::Named(fields) = s.fields() else {
+        panic!("expected named fields");
+    };
+
+    fields
+        .fields()
+        .iter()
+        .map(|(_, field)| field.default_value().cloned())
+        .collect()
+}
+
+#[test]
+fn default_value_metadata() {
+    assert_eq!(
+        default_values::<DefaultValues>(),
+        vec![
+            Some(serde_json::json!(0)),
+            Some(serde_json::json!(50)),
+            Some(serde_json::json!([])),
+            Some(serde_json::json!({ "a": 0 })),
+            None,
+            None,
+        ]
+    );
+
+    // Generic fields don't have a known `Default` or `Serialize` implementation.
+    assert_eq!(default_values::<GenericDefaultValue<i32>>(), vec![None]);
+}
+
+#[test]
+fn default_value_js_doc() {
+    assert_eq!(
+        specta::ts::export::<GenericDefaultValue<i32>>(&Default::default()),
+        Ok("export type GenericDefaultValue<T> = { a?: T }".into())
+    );
+    assert_eq!(
+        specta::ts::export::<DefaultValues>(&Default::default()),
+        Ok("export type DefaultValues = { \n/**\n * @default 0\n */\na?: number; \n/**\n * @default 50\n */\nb?: number; \n/**\n * @default []\n */\nc?: string[]; \n/**\n * @default {\"a\":0}\n */\nd?: NoDefaultValue; e?: NotSerializable; f: string }".into())
+    );
+}
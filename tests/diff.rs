@@ -0,0 +1,252 @@
+use specta::{
+    datatype::diff::{diff, Change, ChangeKind},
+    detect_stable_id_collisions, SerdeMode, Type, TypeMap,
+};
+
+use crate::ts::type_map;
+
+mod v1 {
+    use specta::Type;
+
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub struct User {
+        pub id: i32,
+        pub name: String,
+        pub nickname: Option<String>,
+        pub email: String,
+        pub role: Role,
+    }
+
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub enum Role {
+        Admin,
+        User,
+        Guest,
+    }
+
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub struct Removed(String);
+
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub struct Wrapper<T> {
+        pub value: T,
+        pub result: Result<i32, String>,
+    }
+}
+
+mod v2 {
+    use specta::Type;
+
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub struct User {
+        pub id: String,
+        pub name: Option<String>,
+        #[serde(default)]
+        pub nickname: String,
+        #[serde(rename = "emailAddress")]
+        pub email: String,
+        pub role: Role,
+        #[serde(default)]
+        pub age: i32,
+    }
+
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub enum Role {
+        Admin,
+        User,
+        Moderator,
+    }
+
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub struct Added(String);
+
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub struct Wrapper<T, E> {
+        pub value: T,
+        pub result: Result<i32, E>,
+    }
+}
+
+fn find<'a>(changes: &'a [Change], path: &str) -> Vec<&'a ChangeKind> {
+    changes
+        .iter()
+        .filter(|c| c.path == path)
+        .map(|c| &c.kind)
+        .collect()
+}
+
+#[test]
+fn diff_type_maps() {
+    // `User` depends on `Role` so it's included in the type map.
+    let mut old = type_map::<v1::User>();
+    old.extend(type_map::<v1::Removed>());
+    let mut new = type_map::<v2::User>();
+    new.extend(type_map::<v2::Added>());
+
+    assert_eq!(diff(&old, &old), vec![]);
+
+    let changes = diff(&old, &new);
+    let breaking = |path: &str| {
+        let change = changes.iter().find(|c| c.path == path).unwrap();
+        (
+            change.is_breaking(SerdeMode::Serialize),
+            change.is_breaking(SerdeMode::Deserialize),
+        )
+    };
+
+    assert!(matches!(
+        find(&changes, "User.id")[..],
+        [ChangeKind::TypeChanged { .. }]
+    ));
+    assert_eq!(breaking("User.id"), (true, true));
+
+    // Required -> nullable. Old clients can no longer rely on the field.
+    assert!(matches!(
+        find(&changes, "User.name")[..],
+        [
+            ChangeKind::FieldModified { .. },
+            ChangeKind::NullabilityChanged { nullable: true }
+        ]
+    ));
+    let name = changes.iter().filter(|c| c.path == "User.name");
+    assert!(name.clone().any(|c| c.is_breaking(SerdeMode::Serialize)));
+    assert!(!name.clone().any(|c| c.is_breaking(SerdeMode::Deserialize)));
+
+    // Nullable -> defaulted. Old clients may still send `null`.
+    assert_eq!(
+        find(&changes, "User.nickname"),
+        vec![&ChangeKind::NullabilityChanged { nullable: false }]
+    );
+    assert_eq!(breaking("User.nickname"), (false, true));
+
+    assert!(matches!(
+        find(&changes, "User.emailAddress")[..],
+        [ChangeKind::FieldRenamed { old_name, .. }] if old_name == "email"
+    ));
+    assert_eq!(breaking("User.emailAddress"), (true, true));
+
+    assert!(matches!(
+        find(&changes, "User.age")[..],
+        [ChangeKind::FieldAdded { .. }]
+    ));
+    assert_eq!(breaking("User.age"), (false, false));
+
+    assert!(matches!(
+        find(&changes, "Role::Moderator")[..],
+        [ChangeKind::VariantRenamed { old_name, .. }] if old_name == "Guest"
+    ));
+    assert_eq!(breaking("Role::Moderator"), (true, true));
+
+    assert_eq!(find(&changes, "Removed"), vec![&ChangeKind::TypeRemoved]);
+    assert_eq!(breaking("Removed"), (true, true));
+    assert_eq!(find(&changes, "Added"), vec![&ChangeKind::TypeAdded]);
+    assert_eq!(breaking("Added"), (false, false));
+
+    assert_eq!(changes.len(), 9);
+    assert_eq!(
+        changes
+            .iter()
+            .find(|c| c.path == "User.emailAddress")
+            .unwrap()
+            .to_string(),
+        "User.emailAddress: field renamed from 'email'"
+    );
+}
+
+#[test]
+fn diff_generics() {
+    let changes = diff(
+        &type_map::<v1::Wrapper<()>>(),
+        &type_map::<v2::Wrapper<(), ()>>(),
+    );
+
+    assert_eq!(changes.len(), 2);
+    assert!(matches!(
+        find(&changes, "Wrapper")[..],
+        [ChangeKind::GenericsChanged { old, new }] if old.len() == 1 && new.len() == 2
+    ));
+    assert_eq!(
+        changes[0].to_string(),
+        "Wrapper: generics changed from <T> to <T, E>"
+    );
+    assert!(!changes[0].is_breaking(SerdeMode::Serialize));
+    assert!(matches!(
+        find(&changes, "Wrapper.result.<err>")[..],
+        [ChangeKind::TypeChanged { .. }]
+    ));
+    assert!(changes[1].is_breaking(SerdeMode::Deserialize));
+}
+
+// Types defined in functions share the module path so these all have the same `StableID`.
+fn local_types_v1() -> TypeMap {
+    #[derive(Type)]
+    #[specta(export = false, rename = "First")]
+    pub struct Local {
+        pub a: i32,
+    }
+
+    type_map::<Local>()
+}
+
+fn other_local_types_v1() -> TypeMap {
+    #[derive(Type)]
+    #[specta(export = false, rename = "Second")]
+    pub struct Local {
+        pub b: String,
+    }
+
+    type_map::<Local>()
+}
+
+fn local_types_v2() -> TypeMap {
+    #[derive(Type)]
+    #[specta(export = false, rename = "First")]
+    pub struct Local {
+        pub a: i32,
+    }
+
+    type_map::<Local>()
+}
+
+fn other_local_types_v2() -> TypeMap {
+    #[derive(Type)]
+    #[specta(export = false, rename = "Second")]
+    pub struct Local {
+        pub b: String,
+    }
+
+    type_map::<Local>()
+}
+
+#[test]
+fn diff_ambiguous_stable_id() {
+    let mut old = local_types_v1();
+    old.extend(other_local_types_v1());
+    assert_eq!(detect_stable_id_collisions(&old).len(), 1);
+
+    // The types are matched by name instead of the shared `StableID`
+    let mut new = local_types_v2();
+    new.extend(other_local_types_v2());
+    assert_eq!(diff(&old, &new), vec![]);
+
+    for (new, removed) in [
+        (local_types_v2(), "Second"),
+        (other_local_types_v2(), "First"),
+    ] {
+        assert_eq!(
+            diff(&old, &new),
+            vec![Change {
+                path: removed.into(),
+                kind: ChangeKind::TypeRemoved,
+            }]
+        );
+    }
+}
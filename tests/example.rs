@@ -0,0 +1,56 @@
+#![cfg(feature = "serde_json")]
+
+use serde::Serialize;
+use specta::{DataType, NamedType, StructFields, Type};
+
+use crate::ts::type_map;
+
+const DEFAULT_NAME: &str = "Oscar";
+
+/// A user
+#[derive(Serialize, Type)]
+#[specta(export = false, example = User { age: 42, name: "Monty".into(), tags: vec![] })]
+pub struct User {
+    #[specta(example = 42)]
+    age: u8,
+    #[specta(example = DEFAULT_NAME)]
+    name: String,
+    #[specta(example = vec!["admin", "staff"])]
+    tags: Vec<String>,
+}
+
+#[test]
+fn example_metadata() {
+    let ty = type_map::<User>()[&User::SID].clone().unwrap();
+    assert_eq!(
+        ty.example(),
+        Some(&serde_json::json!({ "name": "Monty", "age": 42, "tags": [] }))
+    );
+
+    let DataType::Struct(s) = &ty.inner else {
+        panic!("expected struct");
+    };
+    let StructFields::Named(fields) = s.fields() else {
+        panic!("expected named fields");
+    };
+    assert_eq!(
+        fields
+            .fields()
+            .iter()
+            .map(|(_, field)| field.example().cloned())
+            .collect::<Vec<_>>(),
+        vec![
+            Some(serde_json::json!(42)),
+            Some(serde_json::json!("Oscar")),
+            Some(serde_json::json!(["admin", "staff"])),
+        ]
+    );
+}
+
+#[test]
+fn example_js_doc() {
+    assert_eq!(
+        specta::ts::export::<User>(&Default::default()),
+        Ok("/**\n * A user\n * @example {\"age\":42,\"name\":\"Monty\",\"tags\":[]}\n */\nexport type User = { \n/**\n * @example 42\n */\nage: number; \n/**\n * @example \"Oscar\"\n */\nname: string; \n/**\n * @example [\"admin\",\"staff\"]\n */\ntags: string[] }".into())
+    );
+}
@@ -0,0 +1,109 @@
+#![cfg(all(feature = "export", feature = "typescript"))]
+
+use std::fs;
+
+use specta::{export, ts::ExportError, DiffLine, OutputDiff};
+
+use crate::ts::export_conf;
+
+#[derive(specta::Type)]
+pub struct CheckUser {
+    pub id: i32,
+}
+
+#[test]
+fn output_diff() {
+    let diff = OutputDiff::new("a\nb\nc\nd\ne\nf\ng\n", "a\nb\nc\nD\ne\nf\ng\nh\n");
+    assert!(!diff.is_empty());
+    assert_eq!(
+        diff.lines[2..5],
+        [
+            DiffLine::Unchanged("c".into()),
+            DiffLine::Removed("d".into()),
+            DiffLine::Added("D".into()),
+        ]
+    );
+    assert_eq!(diff.to_string(), " b\n c\n-d\n+D\n e\n f\n g\n+h\n");
+
+    assert!(OutputDiff::new("a\nb", "a\nb").is_empty());
+}
+
+#[test]
+fn export_check() {
+    let path = std::env::temp_dir().join("specta_export_check.ts");
+    let path = path.to_str().unwrap();
+    let _ = fs::remove_file(path);
+
+    match export::ts_check_with_cfg(path, &export_conf::<CheckUser>()) {
+        Err(ExportError::OutOfDate(p, diff)) => {
+            assert_eq!(p, path);
+            assert!(diff
+                .lines
+                .iter()
+                .all(|line| matches!(line, DiffLine::Added(_))));
+        }
+        result => panic!("expected the missing file to be out of date, got {result:?}"),
+    }
+
+    export::ts_with_cfg(path, &export_conf::<CheckUser>()).unwrap();
+    assert_eq!(
+        export::ts_check_with_cfg(path, &export_conf::<CheckUser>()),
+        Ok(())
+    );
+
+    let content = fs::read_to_string(path).unwrap();
+    fs::write(path, content.replace("id: number", "id: string")).unwrap();
+    let err = export::ts_check_with_cfg(path, &export_conf::<CheckUser>()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!("'{path}' is out of date. Export the types again to update it:\n // This file has been generated by Specta. DO NOT EDIT.\n \n-export type CheckUser = {{ id: string }}\n+export type CheckUser = {{ id: number }}\n \n")
+    );
+
+    // Checking never writes to the file
+    assert!(fs::read_to_string(path).unwrap().contains("id: string"));
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn export_check_line_endings() {
+    let path = std::env::temp_dir().join("specta_export_check_line_endings.ts");
+    let path = path.to_str().unwrap();
+
+    export::ts_with_cfg(path, &export_conf::<CheckUser>()).unwrap();
+    let content = fs::read_to_string(path).unwrap();
+
+    // Eg. checked out by git with `core.autocrlf`
+    fs::write(path, content.replace('\n', "\r\n")).unwrap();
+    assert_eq!(
+        export::ts_check_with_cfg(path, &export_conf::<CheckUser>()),
+        Ok(())
+    );
+    assert!(!export::ts_with_cfg(path, &export_conf::<CheckUser>()).unwrap());
+    assert!(fs::read_to_string(path).unwrap().contains("\r\n"));
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn export_check_trailing_newline() {
+    let path = std::env::temp_dir().join("specta_export_check_trailing_newline.ts");
+    let path = path.to_str().unwrap();
+
+    export::ts_with_cfg(path, &export_conf::<CheckUser>()).unwrap();
+    let content = fs::read_to_string(path).unwrap();
+
+    // The types are separated by an empty line so the missing final newline is a changed line
+    fs::write(path, content.strip_suffix('\n').unwrap()).unwrap();
+    match export::ts_check_with_cfg(path, &export_conf::<CheckUser>()) {
+        Err(ExportError::OutOfDate(_, diff)) => {
+            assert!(!diff.is_empty());
+            assert_eq!(diff.lines.last(), Some(&DiffLine::Added("".into())));
+        }
+        result => panic!("expected the file to be out of date, got {result:?}"),
+    }
+    assert!(export::ts_with_cfg(path, &export_conf::<CheckUser>()).unwrap());
+    assert_eq!(fs::read_to_string(path).unwrap(), content);
+
+    fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,195 @@
+#![cfg(feature = "export")]
+
+use specta::{
+    export::{ExportFilter, TypeSelector},
+    NamedType, TypeMap,
+};
+
+use crate::ts::type_map;
+
+mod shared {
+    use specta::Type;
+
+    #[derive(Type)]
+    pub struct FilterProfile {
+        pub avatar: FilterAvatar,
+    }
+
+    #[derive(Type)]
+    pub struct FilterAvatar {
+        pub url: String,
+    }
+}
+
+mod sdk {
+    use specta::Type;
+
+    #[derive(Type)]
+    pub struct FilterUser {
+        pub id: i32,
+        pub profile: super::shared::FilterProfile,
+    }
+}
+
+mod admin {
+    use specta::Type;
+
+    #[derive(Type)]
+    pub struct FilterAdminUser {
+        pub user: super::sdk::FilterUser,
+        pub audit: FilterAuditInternal,
+    }
+
+    #[derive(Type)]
+    pub struct FilterAuditInternal {
+        pub note: String,
+    }
+}
+
+fn names(types: &TypeMap) -> Vec<&str> {
+    let mut names = types
+        .values()
+        .flatten()
+        .map(|ty| ty.name().as_ref())
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+#[test]
+fn type_selector() {
+    let type_map = type_map::<admin::FilterAdminUser>();
+    let user = type_map
+        .get(&sdk::FilterUser::SID)
+        .unwrap()
+        .as_ref()
+        .unwrap();
+
+    assert_eq!(
+        user.ext().unwrap().module_path(),
+        "integration_tests::export_filter::sdk"
+    );
+
+    assert!(TypeSelector::of::<sdk::FilterUser>().matches(user));
+    assert!(!TypeSelector::of::<admin::FilterAdminUser>().matches(user));
+    assert!(TypeSelector::name("FilterUser").matches(user));
+    assert!(TypeSelector::name("Filter*").matches(user));
+    assert!(TypeSelector::name("*User").matches(user));
+    assert!(!TypeSelector::name("*Admin*").matches(user));
+    assert!(TypeSelector::module("integration_tests::export_filter::sdk").matches(user));
+    assert!(TypeSelector::module("integration_tests::*::sdk").matches(user));
+    assert!(TypeSelector::module("integration_tests::**").matches(user));
+    assert!(TypeSelector::module("**::sdk").matches(user));
+    assert!(TypeSelector::module("**::export_filter::**").matches(user));
+    assert!(TypeSelector::module("*_tests::export_*::s*").matches(user));
+    assert!(!TypeSelector::module("integration_tests::*").matches(user));
+    assert!(!TypeSelector::module("**::admin").matches(user));
+}
+
+#[test]
+fn export_filter() {
+    let type_map = type_map::<admin::FilterAdminUser>();
+
+    // No roots selects everything
+    assert_eq!(
+        names(&ExportFilter::new().apply(&type_map, &type_map)).len(),
+        5
+    );
+
+    // Roots pull in their dependencies
+    assert_eq!(
+        names(
+            &ExportFilter::new()
+                .root(TypeSelector::of::<sdk::FilterUser>())
+                .apply(&type_map, &type_map)
+        ),
+        ["FilterAvatar", "FilterProfile", "FilterUser"]
+    );
+    assert_eq!(
+        names(
+            &ExportFilter::new()
+                .root(TypeSelector::module("**::shared"))
+                .apply(&type_map, &type_map)
+        ),
+        ["FilterAvatar", "FilterProfile"]
+    );
+
+    // Roots must be exported but their dependencies don't need to be
+    let exported = type_map
+        .iter()
+        .filter(|(sid, _)| **sid != sdk::FilterUser::SID)
+        .map(|(sid, ty)| (*sid, ty.clone()))
+        .collect::<TypeMap>();
+    assert_eq!(
+        names(
+            &ExportFilter::new()
+                .root(TypeSelector::of::<sdk::FilterUser>())
+                .apply(&exported, &type_map)
+        ),
+        Vec::<&str>::new()
+    );
+    assert_eq!(
+        names(
+            &ExportFilter::new()
+                .root(TypeSelector::name("Filter*User"))
+                .exclude(TypeSelector::name("*Internal"))
+                .apply(&exported, &type_map)
+        ),
+        [
+            "FilterAdminUser",
+            "FilterAvatar",
+            "FilterProfile",
+            "FilterUser"
+        ]
+    );
+
+    // Exclusions apply after the dependencies are resolved
+    assert_eq!(
+        names(
+            &ExportFilter::new()
+                .root(TypeSelector::module("**::admin"))
+                .exclude(TypeSelector::name("*Internal"))
+                .exclude(TypeSelector::module("**::shared"))
+                .apply(&type_map, &type_map)
+        ),
+        ["FilterAdminUser", "FilterUser"]
+    );
+    assert_eq!(
+        names(
+            &ExportFilter::new()
+                .exclude(sdk::FilterUser::SID)
+                .apply(&type_map, &type_map)
+        ),
+        [
+            "FilterAdminUser",
+            "FilterAuditInternal",
+            "FilterAvatar",
+            "FilterProfile"
+        ]
+    );
+}
+
+#[cfg(feature = "typescript")]
+#[test]
+fn ts_with_cfg_filter() {
+    use specta::{export, ts::ExportConfig};
+
+    let path = std::env::temp_dir().join("specta_export_filter.ts");
+    export::ts_with_cfg(
+        path.to_str().unwrap(),
+        &ExportConfig::default().filter(
+            ExportFilter::new()
+                .root(TypeSelector::of::<sdk::FilterUser>())
+                .exclude(TypeSelector::name("FilterAvatar")),
+        ),
+    )
+    .unwrap();
+
+    let output = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(output.contains("export type FilterUser = { id: number; profile: FilterProfile }"));
+    assert!(output.contains("export type FilterProfile = { avatar: FilterAvatar }"));
+    assert!(!output.contains("export type FilterAvatar"));
+    assert!(!output.contains("export type FilterAdminUser"));
+}
@@ -0,0 +1,67 @@
+#![cfg(all(feature = "export", feature = "typescript"))]
+
+use std::{fs, io, path::PathBuf};
+
+use specta::export;
+
+use crate::ts::export_conf;
+
+#[derive(specta::Type)]
+pub struct IncrementalUser {
+    pub id: i32,
+}
+
+fn format(path: PathBuf) -> io::Result<()> {
+    let content = fs::read_to_string(&path)?;
+    fs::write(path, content.replace("export type", "export  type"))
+}
+
+#[test]
+fn export_incremental() {
+    let path = std::env::temp_dir().join("specta_export_incremental.ts");
+    let path = path.to_str().unwrap();
+    let _ = fs::remove_file(path);
+
+    assert!(export::ts_with_cfg(path, &export_conf::<IncrementalUser>()).unwrap());
+    assert!(!export::ts_with_cfg(path, &export_conf::<IncrementalUser>()).unwrap());
+    assert_eq!(
+        fs::read_to_string(path).unwrap(),
+        "// This file has been generated by Specta. DO NOT EDIT.\n\nexport type IncrementalUser = { id: number }\n\n"
+    );
+
+    fs::write(path, "outdated").unwrap();
+    assert!(export::ts_with_cfg(path, &export_conf::<IncrementalUser>()).unwrap());
+    assert!(!export::ts_with_cfg(path, &export_conf::<IncrementalUser>()).unwrap());
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn export_incremental_with_formatter() {
+    let path = std::env::temp_dir().join("specta_export_incremental_formatter.ts");
+    let path = path.to_str().unwrap();
+    let _ = fs::remove_file(path);
+
+    // The formatted file doesn't match the output but the hash in the header does
+    assert!(
+        export::ts_with_cfg(path, &export_conf::<IncrementalUser>().formatter(format)).unwrap()
+    );
+    assert!(
+        !export::ts_with_cfg(path, &export_conf::<IncrementalUser>().formatter(format)).unwrap()
+    );
+
+    let content = fs::read_to_string(path).unwrap();
+    assert!(content.contains("// Specta hash: "));
+    assert!(content.contains("export  type IncrementalUser = { id: number }"));
+
+    // Changing the output changes the hash
+    assert!(export::ts_with_cfg(
+        path,
+        &export_conf::<IncrementalUser>()
+            .formatter(format)
+            .readonly(true)
+    )
+    .unwrap());
+
+    fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "export")]
+
+use specta::export::ExportOrder;
+
+use crate::ts::type_map;
+
+mod animals {
+    use specta::Type;
+
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub struct OrderZebra {
+        pub apple: super::fruit::OrderApple,
+        pub node: super::tree::OrderNode,
+    }
+}
+
+mod fruit {
+    use specta::Type;
+
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub struct OrderApple {
+        pub banana: OrderBanana,
+    }
+
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub struct OrderBanana {
+        pub value: String,
+    }
+}
+
+mod tree {
+    use specta::Type;
+
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub struct OrderNode {
+        pub children: Vec<OrderNode>,
+        pub tree: Option<Box<OrderTree>>,
+    }
+
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub struct OrderTree {
+        pub root: OrderNode,
+    }
+}
+
+fn names(order: ExportOrder) -> Vec<String> {
+    let type_map = type_map::<animals::OrderZebra>();
+
+    order
+        .sort(&type_map)
+        .into_iter()
+        .map(|ty| ty.name().to_string())
+        .collect()
+}
+
+#[test]
+fn export_order() {
+    assert_eq!(names(ExportOrder::Sid).len(), 5);
+    assert_eq!(
+        names(ExportOrder::Alphabetical),
+        [
+            "OrderApple",
+            "OrderBanana",
+            "OrderNode",
+            "OrderTree",
+            "OrderZebra"
+        ]
+    );
+    assert_eq!(
+        names(ExportOrder::Topological),
+        [
+            "OrderBanana",
+            "OrderApple",
+            "OrderTree",
+            "OrderNode",
+            "OrderZebra"
+        ]
+    );
+    assert_eq!(
+        names(ExportOrder::Module),
+        [
+            "OrderZebra",
+            "OrderApple",
+            "OrderBanana",
+            "OrderNode",
+            "OrderTree"
+        ]
+    );
+}
@@ -0,0 +1,38 @@
+#![cfg(all(feature = "export", feature = "typescript"))]
+
+use specta::export;
+
+use crate::ts::export_conf;
+
+#[derive(specta::Type)]
+pub struct StringUser {
+    pub id: i32,
+}
+
+#[test]
+fn export_to_string() {
+    assert_eq!(
+        export::ts_to_string(&export_conf::<StringUser>()).unwrap(),
+        "// This file has been generated by Specta. DO NOT EDIT.\n\nexport type StringUser = { id: number }\n\n"
+    );
+    assert_eq!(
+        export::ts_to_string(&export_conf::<StringUser>().header("/* eslint-disable */\n// Generated by build.rs")).unwrap(),
+        "/* eslint-disable */\n// Generated by build.rs\n\nexport type StringUser = { id: number }\n\n"
+    );
+    assert_eq!(
+        export::ts_to_string(&export_conf::<StringUser>().header("")).unwrap(),
+        "export type StringUser = { id: number }\n\n"
+    );
+    // The hash used to detect if a formatted file is up to date is only added to the file
+    assert_eq!(
+        export::ts_to_string(&export_conf::<StringUser>().formatter(|_| Ok(()))).unwrap(),
+        export::ts_to_string(&export_conf::<StringUser>()).unwrap()
+    );
+
+    let mut out = Vec::new();
+    export::ts_to_writer(&mut out, &export_conf::<StringUser>()).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        export::ts_to_string(&export_conf::<StringUser>()).unwrap()
+    );
+}
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, sync::Arc};
 
-use specta::Type;
+use specta::{ts::ExportPath, SerdeError, Type};
 
 use crate::ts::assert_ts;
 
@@ -116,15 +116,13 @@ pub enum K {
 
 #[test]
 fn serde() {
-    assert_ts!(
-        B,
-        "({ a: string }) & ({ [key in string]: string }) & ({ a: string })"
-    );
+    // Serde would emit the `a` key twice
+    assert_ts!(error; B, SerdeError::DuplicateFieldName(ExportPath::new_unsafe("B"), "a".into()));
     assert_ts!(C, "({ a: string }) & { b: { a: string } }");
     assert_ts!(D, "({ a: string }) & { b: { a: string }; type: \"D\" }");
     assert_ts!(E, "({ a: string }) & { b: { a: string } }");
-    assert_ts!(F, "({ a: string }) & ({ a: string })");
-    assert_ts!(G, "({ a: string }) & ({ a: number })");
+    assert_ts!(error; F, SerdeError::DuplicateFieldName(ExportPath::new_unsafe("F"), "a".into()));
+    assert_ts!(error; G, SerdeError::DuplicateFieldName(ExportPath::new_unsafe("G"), "a".into()));
     assert_ts!(H, "{ A: string } | \"B\"");
     assert_ts!(J, "{ t: \"A\"; c: string } | { t: \"B\" } | { t: \"C\"; c: { a: string } } | { t: \"D\"; c: A }");
     assert_ts!(K, "string | null | { a: string } | A");
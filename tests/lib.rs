@@ -2,25 +2,43 @@
 
 mod advanced_types;
 mod bigints;
+mod builder;
+mod collect_errors;
 mod comments;
+mod constraints;
 mod datatype;
+mod default_value;
 mod deprecated;
+mod diff;
 mod duplicate_ty_name;
+mod example;
 mod export;
+mod export_check;
+mod export_filter;
+mod export_incremental;
+mod export_order;
+mod export_to_string;
 mod flatten_and_inline;
 mod functions;
 mod macro_decls;
 mod map_keys;
+mod metadata;
 mod optional;
+mod readonly;
 mod rename;
 mod reserved_keywords;
+mod schema;
 mod selection;
 mod serde;
+mod serde_mode;
 mod sid;
+mod string_format;
 mod transparent;
 pub mod ts;
 mod ts_rs;
 mod ty_override;
+mod visit;
+mod warnings;
 
 #[test]
 fn test_compile_errors() {
@@ -103,4 +103,23 @@ pub struct InvalidSpectaAttribute1;
 #[specta = "todo"]
 pub struct InvalidSpectaAttribute2;
 
+// Custom serializers require a type override
+#[derive(Type)]
+pub struct CustomSerializer1 {
+    #[serde(with = "duration_millis")]
+    a: std::time::Duration,
+}
+
+#[derive(Type)]
+pub struct CustomSerializer2 {
+    #[serde(serialize_with = "serialize_as_string")]
+    a: i32,
+}
+
+#[derive(Type)]
+pub enum CustomSerializer3 {
+    #[serde(deserialize_with = "deserialize_variant")]
+    A(i32),
+}
+
 // TODO: https://docs.rs/trybuild/latest/trybuild/#what-to-test
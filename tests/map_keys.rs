@@ -1,6 +1,6 @@
 use std::{collections::HashMap, convert::Infallible};
 
-use specta::{Any, SerdeError, Type};
+use specta::{ts::ExportPath, Any, SerdeError, Type};
 
 use crate::ts::{assert_ts, assert_ts_export};
 
@@ -81,9 +81,9 @@ fn map_keys() {
         "export type ValidMaybeValidKey = { [key in MaybeValidKey<string>]: null }"
     );
 
-    assert_ts!(error; HashMap<() /* `null` */, ()>, SerdeError::InvalidMapKey);
-    assert_ts!(error; HashMap<RegularStruct, ()>, SerdeError::InvalidMapKey);
-    assert_ts!(error; HashMap<Variants, ()>, SerdeError::InvalidMapKey);
-    assert_ts!(error; InvalidMaybeValidKey, SerdeError::InvalidMapKey);
-    assert_ts_export!(error; InvalidMaybeValidKey, SerdeError::InvalidMapKey);
+    assert_ts!(error; HashMap<() /* `null` */, ()>, SerdeError::InvalidMapKey(ExportPath::new_unsafe("")));
+    assert_ts!(error; HashMap<RegularStruct, ()>, SerdeError::InvalidMapKey(ExportPath::new_unsafe("")));
+    assert_ts!(error; HashMap<Variants, ()>, SerdeError::InvalidMapKey(ExportPath::new_unsafe("")));
+    assert_ts!(error; InvalidMaybeValidKey, SerdeError::InvalidMapKey(ExportPath::new_unsafe("MaybeValidKey")));
+    assert_ts_export!(error; InvalidMaybeValidKey, SerdeError::InvalidMapKey(ExportPath::new_unsafe("MaybeValidKey")));
 }
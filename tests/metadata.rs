@@ -0,0 +1,73 @@
+use specta::{
+    ts::{CommentFormatterArgs, ExportConfig},
+    DataType, EnumVariants, NamedType, StructFields,
+};
+
+use crate::ts::type_map;
+
+#[derive(specta::Type)]
+#[specta(export = false, meta(permission = "admin", ui = "table"))]
+pub struct Account {
+    #[specta(meta(ui = "password"))]
+    password: String,
+    name: String,
+}
+
+#[derive(specta::Type)]
+#[specta(export = false)]
+pub enum Role {
+    #[specta(meta(permission = "admin"))]
+    Admin {
+        id: i32,
+    },
+    User(#[specta(meta(ui = "hidden"))] i32),
+}
+
+#[test]
+fn metadata() {
+    let ty = type_map::<Account>()[&Account::SID].clone().unwrap();
+    assert_eq!(
+        ty.metadata().iter().collect::<Vec<_>>(),
+        vec![("permission", "admin"), ("ui", "table")]
+    );
+    assert_eq!(ty.metadata().get("ui"), Some("table"));
+    assert_eq!(ty.metadata().get("missing"), None);
+
+    let DataType::Struct(s) = &ty.inner else {
+        panic!("expected struct");
+    };
+    let StructFields::Named(fields) = s.fields() else {
+        panic!("expected named fields");
+    };
+    assert_eq!(fields.fields()[0].1.metadata().get("ui"), Some("password"));
+    assert!(fields.fields()[1].1.metadata().is_empty());
+
+    let ty = type_map::<Role>()[&Role::SID].clone().unwrap();
+    assert!(ty.metadata().is_empty());
+    let DataType::Enum(e) = &ty.inner else {
+        panic!("expected enum");
+    };
+    let variants = e.variants();
+    assert_eq!(variants[0].1.metadata().get("permission"), Some("admin"));
+    let EnumVariants::Unnamed(fields) = variants[1].1.inner() else {
+        panic!("expected unnamed variant");
+    };
+    assert_eq!(fields.fields()[0].metadata().get("ui"), Some("hidden"));
+}
+
+fn metadata_comments(args: CommentFormatterArgs) -> String {
+    args.metadata
+        .iter()
+        .map(|(k, v)| format!("// {k}: {v}\n"))
+        .collect()
+}
+
+#[test]
+fn metadata_comment_formatter() {
+    assert_eq!(
+        specta::ts::export::<Account>(
+            &ExportConfig::default().comment_style(Some(metadata_comments))
+        ),
+        Ok("// permission: admin\n// ui: table\nexport type Account = { \n// ui: password\npassword: string; name: string }".into())
+    );
+}
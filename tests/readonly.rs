@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use specta::{ts::ExportConfig, Type};
+
+#[derive(Type)]
+#[specta(export = false)]
+struct Readonly {
+    a: String,
+    b: Vec<i32>,
+    c: HashMap<String, Vec<String>>,
+    #[specta(optional)]
+    d: Option<Vec<Option<i32>>>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct ReadonlyFieldOverride {
+    a: String,
+    #[specta(readonly = false)]
+    b: Vec<i32>,
+}
+
+#[derive(Type)]
+#[specta(export = false, readonly = false)]
+struct ReadonlyContainerOverride {
+    a: String,
+    #[specta(readonly)]
+    b: Vec<i32>,
+}
+
+#[derive(Type)]
+#[specta(export = false, tag = "type")]
+enum ReadonlyEnum {
+    A { a: Vec<String> },
+    B,
+}
+
+#[derive(Type)]
+#[specta(export = false, readonly = false)]
+#[serde(tag = "type")]
+struct ReadonlyTaggedOverride {
+    a: String,
+}
+
+#[derive(Type)]
+#[specta(export = false, readonly)]
+#[serde(tag = "type")]
+struct ReadonlyTagged {
+    a: String,
+}
+
+#[derive(Type)]
+#[specta(export = false, readonly = false, tag = "type")]
+enum ReadonlyEnumOverride {
+    A { a: Vec<String> },
+    B,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+struct ReadonlyTuple(i32, (String, bool));
+
+#[test]
+fn readonly() {
+    let cfg = ExportConfig::default().readonly(true);
+
+    assert_eq!(
+        specta::ts::inline::<Readonly>(&Default::default()),
+        Ok("{ a: string; b: number[]; c: { [key in string]: string[] }; d?: (number | null)[] | null }".into())
+    );
+    assert_eq!(
+        specta::ts::inline::<Readonly>(&cfg),
+        Ok("{ readonly a: string; readonly b: readonly number[]; readonly c: Readonly<{ [key in string]: readonly string[] }>; readonly d?: readonly (number | null)[] | null }".into())
+    );
+    assert_eq!(
+        specta::ts::inline::<ReadonlyFieldOverride>(&cfg),
+        Ok("{ readonly a: string; b: number[] }".into())
+    );
+    assert_eq!(
+        specta::ts::inline::<ReadonlyContainerOverride>(&cfg),
+        Ok("{ a: string; readonly b: readonly number[] }".into())
+    );
+    assert_eq!(
+        specta::ts::inline::<ReadonlyContainerOverride>(&Default::default()),
+        Ok("{ a: string; readonly b: readonly number[] }".into())
+    );
+    assert_eq!(
+        specta::ts::inline::<ReadonlyEnum>(&cfg),
+        Ok(
+            r#"{ readonly type: "A"; readonly a: readonly string[] } | { readonly type: "B" }"#
+                .into()
+        )
+    );
+    assert_eq!(
+        specta::ts::inline::<ReadonlyTaggedOverride>(&cfg),
+        Ok(r#"{ a: string; type: "ReadonlyTaggedOverride" }"#.into())
+    );
+    assert_eq!(
+        specta::ts::inline::<ReadonlyTagged>(&Default::default()),
+        Ok(r#"{ readonly a: string; readonly type: "ReadonlyTagged" }"#.into())
+    );
+    assert_eq!(
+        specta::ts::inline::<ReadonlyEnumOverride>(&cfg),
+        Ok(r#"{ type: "A"; a: string[] } | { type: "B" }"#.into())
+    );
+    assert_eq!(
+        specta::ts::inline::<ReadonlyTuple>(&cfg),
+        Ok("readonly [number, readonly [string, boolean]]".into())
+    );
+    assert_eq!(
+        specta::ts::inline::<ReadonlyTuple>(&Default::default()),
+        Ok("[number, [string, boolean]]".into())
+    );
+}
@@ -39,6 +39,20 @@ pub enum Enum3 {
     },
 }
 
+#[derive(Type)]
+#[specta(export = false, rename_all_fields = "camelCase")]
+pub enum Enum4 {
+    A {
+        a_b: String,
+    },
+    #[specta(rename_all = "SCREAMING_SNAKE_CASE")]
+    B {
+        c_d: String,
+        #[specta(rename = "e")]
+        e_f: String,
+    },
+}
+
 #[test]
 fn rename() {
     assert_ts!(Struct, "{ a: string; t: \"StructNew\" }");
@@ -54,4 +68,8 @@ fn rename() {
 
     assert_ts!(Enum2, "{ t: \"C\" } | { t: \"B\" }");
     assert_ts!(Enum3, "{ t: \"A\"; b: string }");
+    assert_ts!(
+        Enum4,
+        "{ A: { aB: string } } | { B: { C_D: string; e: string } }"
+    );
 }
@@ -0,0 +1,70 @@
+#![cfg(feature = "schema")]
+
+use std::collections::HashMap;
+
+use specta::{datatype::Schema, Type};
+
+use crate::ts::type_map;
+
+#[derive(Type)]
+#[specta(export = false)]
+/// A user
+pub struct User {
+    #[specta(meta(ui = "hidden"))]
+    id: u64,
+    name: String,
+    #[deprecated = "Use `name`"]
+    nickname: Option<String>,
+    tags: Vec<Tag>,
+    scores: HashMap<String, (f32, bool)>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+pub enum Tag {
+    Unit,
+    Unnamed(i32, String),
+    Named { label: String },
+}
+
+#[test]
+fn schema_roundtrip() {
+    let type_map = type_map::<User>();
+    assert_eq!(type_map.len(), 2);
+
+    let json = serde_json::to_string(&Schema::new(&type_map)).unwrap();
+    let schema: Schema = serde_json::from_str(&json).unwrap();
+    assert_eq!(schema.version, Schema::VERSION);
+    assert_eq!(schema.into_type_map(), type_map);
+}
+
+#[test]
+fn schema_version() {
+    let mut json = serde_json::to_value(Schema::new(&type_map::<User>())).unwrap();
+    assert_eq!(json["version"], Schema::VERSION);
+
+    json["version"] = (Schema::VERSION + 1).into();
+    assert!(serde_json::from_value::<Schema>(json).is_err());
+}
+
+// The serialized format depends on the internal field names of the model so this catches accidental breaking changes.
+// A `Schema::VERSION` bump should add a new fixture and keep the old ones loading.
+#[test]
+fn schema_fixture() {
+    let fixture = include_str!("./fixtures/schema_v1.json");
+
+    let schema: Schema = serde_json::from_str(fixture).unwrap();
+    let type_map = schema.clone().into_type_map();
+    assert_eq!(
+        type_map
+            .values()
+            .flatten()
+            .map(|ty| ty.name().as_ref())
+            .collect::<Vec<_>>(),
+        ["Tag", "User"]
+    );
+    assert_eq!(
+        serde_json::to_string_pretty(&schema).unwrap(),
+        fixture.trim_end()
+    );
+}
@@ -0,0 +1,96 @@
+use specta::{
+    ts::{inline, ExportConfig},
+    SerdeMode, Type,
+};
+
+use crate::ts::{assert_ts, assert_ts_export};
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct Inner {
+    a: i32,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(into = "String")]
+pub struct Email {
+    user: String,
+    domain: String,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(try_from = "String")]
+pub struct Bytes {
+    len: u32,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(from = "Inner", into = "String")]
+pub enum IntoAndFrom {
+    A,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(try_from = "Vec<T>", into = "Inner")]
+pub struct IntoAndTryFrom<T> {
+    items: Vec<T>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(into = "Inner")]
+pub struct IntoNamed {
+    b: String,
+}
+
+#[derive(Type)]
+#[specta(export = false, inline)]
+#[serde(into = "Inner")]
+pub struct IntoNamedInline {
+    b: String,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(into = "Vec<T>")]
+pub struct IntoGeneric<T> {
+    items: Vec<T>,
+    len: usize,
+}
+
+#[test]
+fn conversion() {
+    assert_ts!(Email, "string");
+    assert_ts!(Bytes, "{ len: number }");
+    assert_ts!(IntoAndFrom, "string");
+    assert_ts!(IntoNamed, "Inner");
+    assert_ts_export!(IntoNamed, "export type IntoNamed = Inner");
+    assert_ts!(IntoNamedInline, "{ a: number }");
+    assert_ts!(IntoGeneric<bool>, "boolean[]");
+
+    let serialize = ExportConfig::default().serde_mode(Some(SerdeMode::Serialize));
+    let deserialize = ExportConfig::default().serde_mode(Some(SerdeMode::Deserialize));
+    // Only the direction with an attribute is converted
+    assert_eq!(inline::<Email>(&serialize), Ok("string".into()));
+    assert_eq!(
+        inline::<Email>(&deserialize),
+        Ok("{ user: string; domain: string }".into())
+    );
+    assert_eq!(inline::<Bytes>(&serialize), Ok("{ len: number }".into()));
+    assert_eq!(inline::<Bytes>(&deserialize), Ok("string".into()));
+
+    assert_eq!(inline::<IntoAndFrom>(&serialize), Ok("string".into()));
+    assert_eq!(inline::<IntoAndFrom>(&deserialize), Ok("Inner".into()));
+    assert_eq!(
+        inline::<IntoAndTryFrom<bool>>(&serialize),
+        Ok("Inner".into())
+    );
+    assert_eq!(
+        inline::<IntoAndTryFrom<bool>>(&deserialize),
+        Ok("boolean[]".into())
+    );
+}
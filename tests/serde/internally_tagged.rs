@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use specta::{SerdeError, Type};
+use specta::{ts::ExportPath, SerdeError, Type};
 
 use crate::ts::assert_ts;
 
@@ -121,15 +121,15 @@ pub enum MInner {
 
 #[test]
 fn internally_tagged() {
-    assert_ts!(error; A, SerdeError::InvalidInternallyTaggedEnum);
-    assert_ts!(error; B, SerdeError::InvalidInternallyTaggedEnum);
-    assert_ts!(error; C, SerdeError::InvalidInternallyTaggedEnum);
+    assert_ts!(error; A, SerdeError::InvalidInternallyTaggedEnum(ExportPath::new_unsafe("A::A")));
+    assert_ts!(error; B, SerdeError::InvalidInternallyTaggedEnum(ExportPath::new_unsafe("B::A")));
+    assert_ts!(error; C, SerdeError::InvalidInternallyTaggedEnum(ExportPath::new_unsafe("C::A")));
     assert_ts!(D, "({ type: \"A\" } & { [key in string]: string })");
     assert_ts!(E, "({ type: \"A\" })");
     assert_ts!(F, "({ type: \"A\" } & FInner)");
-    assert_ts!(error; G, SerdeError::InvalidInternallyTaggedEnum);
+    assert_ts!(error; G, SerdeError::InvalidInternallyTaggedEnum(ExportPath::new_unsafe("G::A -> GInner::A")));
     assert_ts!(H, "({ type: \"A\" } & HInner)");
-    assert_ts!(error; I, SerdeError::InvalidInternallyTaggedEnum);
+    assert_ts!(error; I, SerdeError::InvalidInternallyTaggedEnum(ExportPath::new_unsafe("I::A -> IInner")));
     assert_ts!(L, "({ type: \"A\" } & ({ type: \"A\" } | { type: \"B\" }))");
     assert_ts!(M, "({ type: \"A\" })");
 }
@@ -1,5 +1,8 @@
 mod adjacently_tagged;
+mod conversion;
 mod externally_tagged;
 mod internally_tagged;
+mod other;
 mod skip;
 mod untagged;
+mod validation;
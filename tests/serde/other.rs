@@ -0,0 +1,66 @@
+use serde::Deserialize;
+use specta::{ts::ExportConfig, DataType, DefOpts, SerdeMode, StructFields, Type};
+
+use crate::ts::assert_ts;
+
+#[derive(Type, Deserialize)]
+#[specta(export = false)]
+#[serde(deny_unknown_fields)]
+pub struct Aliased {
+    #[serde(alias = "b", alias = "c")]
+    a: String,
+}
+
+#[derive(Type, Deserialize)]
+#[specta(export = false)]
+#[serde(tag = "t")]
+pub enum WithOther {
+    #[serde(alias = "Z")]
+    A,
+    B {
+        b: i32,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+fn inline<T: Type>() -> DataType {
+    T::inline(
+        DefOpts {
+            parent_inline: false,
+            type_map: &mut Default::default(),
+        },
+        &[],
+    )
+}
+
+#[test]
+fn serde_other() {
+    let DataType::Struct(s) = inline::<Aliased>() else {
+        panic!("expected struct");
+    };
+    assert!(s.deny_unknown_fields());
+    let StructFields::Named(fields) = s.fields() else {
+        panic!("expected named fields");
+    };
+    assert_eq!(fields.fields()[0].1.aliases(), &vec!["b", "c"]);
+
+    let DataType::Enum(e) = inline::<WithOther>() else {
+        panic!("expected enum");
+    };
+    assert!(!e.deny_unknown_fields());
+    assert_eq!(e.variants()[0].1.aliases(), &vec!["Z"]);
+    assert!(!e.variants()[0].1.other());
+    assert!(e.variants()[2].1.other());
+
+    assert_ts!(
+        WithOther,
+        r#"{ t: "A" } | { t: "B"; b: number } | { t: "Unknown" }"#
+    );
+    assert_eq!(
+        specta::ts::inline::<WithOther>(
+            &ExportConfig::default().serde_mode(Some(SerdeMode::Deserialize))
+        ),
+        Ok(r#"{ t: "A" } | { t: "B"; b: number } | { t: string }"#.into())
+    );
+}
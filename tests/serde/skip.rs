@@ -1,4 +1,4 @@
-use specta::{SerdeError, Type};
+use specta::{ts::ExportPath, SerdeError, Type};
 
 use crate::ts::assert_ts;
 
@@ -84,10 +84,10 @@ pub enum SkipNamedFieldInVariant {
 fn skip() {
     assert_ts!(SkipOnlyField, "Record<string, never>");
     assert_ts!(SkipField, "{ b: number }");
-    assert_ts!(error; SkipOnlyVariantExternallyTagged, SerdeError::InvalidUsageOfSkip);
-    assert_ts!(error; SkipOnlyVariantInternallyTagged, SerdeError::InvalidUsageOfSkip);
-    assert_ts!(error; SkipOnlyVariantAdjacentlyTagged, SerdeError::InvalidUsageOfSkip);
-    assert_ts!(error; SkipOnlyVariantUntagged, SerdeError::InvalidUsageOfSkip);
+    assert_ts!(error; SkipOnlyVariantExternallyTagged, SerdeError::InvalidUsageOfSkip(ExportPath::new_unsafe("SkipOnlyVariantExternallyTagged")));
+    assert_ts!(error; SkipOnlyVariantInternallyTagged, SerdeError::InvalidUsageOfSkip(ExportPath::new_unsafe("SkipOnlyVariantInternallyTagged")));
+    assert_ts!(error; SkipOnlyVariantAdjacentlyTagged, SerdeError::InvalidUsageOfSkip(ExportPath::new_unsafe("SkipOnlyVariantAdjacentlyTagged")));
+    assert_ts!(error; SkipOnlyVariantUntagged, SerdeError::InvalidUsageOfSkip(ExportPath::new_unsafe("SkipOnlyVariantUntagged")));
     assert_ts!(SkipVariant, "{ B: number }"); // Serializing `A` will be error but that is expected behavior.
     assert_ts!(SkipUnnamedFieldInVariant, r#""A" | { B: [number] }"#);
     assert_ts!(
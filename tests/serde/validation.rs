@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use specta::{
+    ts::{self, ExportConfig, ExportError, ExportPath},
+    DataType, DataTypeReference, NamedDataType, SerdeError, SpectaID, StructType, Type, TypeMap,
+};
+
+use crate::ts::assert_ts_export;
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct Outer {
+    inner: Inner,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct Inner {
+    map: HashMap<(), ()>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct Recursive {
+    children: Vec<Recursive>,
+    map: HashMap<String, Recursive>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct FlattenTransparent {
+    #[serde(flatten)]
+    value: Transparent,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(transparent)]
+pub struct Transparent(String);
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct FlattenTupleStruct {
+    #[serde(flatten)]
+    value: Tuple,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct Tuple(String, i32);
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct Renamed {
+    a: String,
+    #[serde(rename = "a")]
+    b: String,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameAll {
+    user_id: String,
+    #[serde(rename = "userId")]
+    other: String,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(tag = "kind")]
+pub struct StructTag {
+    kind: String,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(tag = "kind")]
+pub enum EnumTag {
+    A { kind: String },
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct HasKind {
+    kind: String,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(tag = "kind")]
+pub enum EnumTagThroughReference {
+    A(HasKind),
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(tag = "kind")]
+pub enum InternallyTaggedTupleStruct {
+    A(Tuple),
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[serde(tag = "kind", content = "kind")]
+pub enum AdjacentTagIsContent {
+    A(String),
+}
+
+#[test]
+fn validation() {
+    assert_ts_export!(
+        error;
+        Outer,
+        SerdeError::InvalidMapKey(ExportPath::new_unsafe("Outer.inner -> Inner.map"))
+    );
+
+    assert_ts_export!(
+        Recursive,
+        "export type Recursive = { children: Recursive[]; map: { [key in string]: Recursive } }"
+    );
+
+    assert_ts_export!(
+        error;
+        FlattenTransparent,
+        SerdeError::InvalidFlatten(ExportPath::new_unsafe("FlattenTransparent.value"))
+    );
+    assert_ts_export!(
+        error;
+        FlattenTupleStruct,
+        SerdeError::InvalidFlatten(ExportPath::new_unsafe("FlattenTupleStruct.value"))
+    );
+    assert_ts_export!(
+        error;
+        Renamed,
+        SerdeError::DuplicateFieldName(ExportPath::new_unsafe("Renamed"), "a".into())
+    );
+    assert_ts_export!(
+        error;
+        RenameAll,
+        SerdeError::DuplicateFieldName(ExportPath::new_unsafe("RenameAll"), "userId".into())
+    );
+    assert_ts_export!(
+        error;
+        StructTag,
+        SerdeError::TagCollision(ExportPath::new_unsafe("StructTag"), "kind".into())
+    );
+    assert_ts_export!(
+        error;
+        EnumTag,
+        SerdeError::TagCollision(ExportPath::new_unsafe("EnumTag::A"), "kind".into())
+    );
+    assert_ts_export!(
+        error;
+        EnumTagThroughReference,
+        SerdeError::TagCollision(ExportPath::new_unsafe("EnumTagThroughReference::A"), "kind".into())
+    );
+    assert_ts_export!(
+        error;
+        InternallyTaggedTupleStruct,
+        SerdeError::InvalidInternallyTaggedEnum(ExportPath::new_unsafe("InternallyTaggedTupleStruct::A -> Tuple"))
+    );
+    assert_ts_export!(
+        error;
+        AdjacentTagIsContent,
+        SerdeError::TagCollision(ExportPath::new_unsafe("AdjacentTagIsContent"), "kind".into())
+    );
+
+    let missing = NamedDataType::builder(
+        "Broken",
+        StructType::builder("Broken")
+            .field(
+                "missing",
+                DataType::Reference(DataTypeReference::new(
+                    "Missing",
+                    SpectaID::new("Missing", "validation"),
+                    vec![],
+                )),
+            )
+            .build(),
+    )
+    .build();
+    assert_eq!(
+        ts::export_named_datatype(&ExportConfig::default(), &missing, &TypeMap::default()),
+        Err(ExportError::Serde(SerdeError::ReferenceNotFound(
+            ExportPath::new_unsafe("Broken.missing"),
+            "Missing".into()
+        )))
+    );
+}
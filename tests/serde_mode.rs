@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use specta::{ts::ExportConfig, SerdeMode, Type};
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(export = false)]
+struct User {
+    id: i32,
+    #[serde(skip_deserializing)]
+    created_at: String,
+    #[serde(skip_serializing)]
+    password: String,
+    #[serde(default)]
+    admin: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nickname: Option<String>,
+    bio: Option<String>,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(export = false)]
+struct UserRef {
+    user: User,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(export = false)]
+enum Event {
+    A,
+    #[serde(skip_serializing)]
+    B,
+    #[serde(skip_deserializing)]
+    C,
+}
+
+#[test]
+fn serde_mode() {
+    assert_eq!(
+        specta::ts::inline::<User>(&Default::default()),
+        Ok("{ id: number; admin?: boolean; nickname?: string | null; bio: string | null }".into())
+    );
+    assert_eq!(
+        specta::ts::inline::<User>(&ExportConfig::default().serde_mode(Some(SerdeMode::Deserialize))),
+        Ok("{ id: number; password: string; admin?: boolean; nickname?: string | null; bio?: string | null }".into())
+    );
+    assert_eq!(
+        specta::ts::inline::<User>(&ExportConfig::default().serde_mode(Some(SerdeMode::Serialize))),
+        Ok("{ id: number; created_at: string; admin: boolean; nickname?: string | null; bio: string | null }".into())
+    );
+
+    assert_eq!(
+        specta::ts::inline::<Event>(&Default::default()),
+        Ok(r#""A""#.into())
+    );
+    assert_eq!(
+        specta::ts::inline::<Event>(
+            &ExportConfig::default().serde_mode(Some(SerdeMode::Deserialize))
+        ),
+        Ok(r#""A" | "B""#.into())
+    );
+    assert_eq!(
+        specta::ts::inline::<Event>(
+            &ExportConfig::default().serde_mode(Some(SerdeMode::Serialize))
+        ),
+        Ok(r#""A" | "C""#.into())
+    );
+}
+
+#[test]
+fn serde_mode_suffix() {
+    let cfg = ExportConfig::default().serde_mode_suffix(true);
+
+    assert_eq!(
+        specta::ts::export::<UserRef>(&cfg),
+        Ok("export type UserRef = { user: User }".into())
+    );
+    assert_eq!(
+        specta::ts::export::<UserRef>(&cfg.clone().serde_mode(Some(SerdeMode::Deserialize))),
+        Ok("export type UserRefInput = { user: UserInput }".into())
+    );
+    assert_eq!(
+        specta::ts::export::<UserRef>(&cfg.serde_mode(Some(SerdeMode::Serialize))),
+        Ok("export type UserRefOutput = { user: UserOutput }".into())
+    );
+}
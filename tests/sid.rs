@@ -1,4 +1,6 @@
-use specta::{DefOpts, Type, TypeMap};
+use specta::{detect_stable_id_collisions, DefOpts, StableID, Type, TypeMap};
+
+use crate::ts::type_map;
 
 #[derive(Type)]
 #[specta(export = false)]
@@ -16,6 +18,10 @@ pub struct C {}
 #[specta(export = false)]
 pub struct Z {}
 
+#[derive(Type)]
+#[specta(export = false, rename = "Renamed")]
+pub struct D {}
+
 #[derive(Type)]
 #[specta(export = false)]
 pub struct BagOfTypes {
@@ -45,3 +51,48 @@ fn test_sid() {
         ["A", "B", "C", "Z"]
     );
 }
+
+fn local_a() -> TypeMap {
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub struct Local {}
+
+    type_map::<Local>()
+}
+
+fn local_b() -> TypeMap {
+    #[derive(Type)]
+    #[specta(export = false)]
+    pub struct Local {}
+
+    type_map::<Local>()
+}
+
+#[test]
+fn test_stable_id() {
+    let stable_id = |type_map: TypeMap| {
+        type_map
+            .into_values()
+            .flatten()
+            .map(|ty| ty.ext().unwrap().stable_id().clone())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        stable_id(type_map::<A>()),
+        [StableID::new("integration_tests::sid::A")]
+    );
+    // The `StableID` uses the Rust name so it isn't affected by renames
+    assert_eq!(
+        stable_id(type_map::<D>()),
+        [StableID::new("integration_tests::sid::D")]
+    );
+    assert_eq!(stable_id(local_a()), stable_id(local_b()));
+
+    assert_eq!(detect_stable_id_collisions(&type_map::<BagOfTypes>()), []);
+    let mut type_map = local_a();
+    type_map.extend(local_b());
+    let collisions = detect_stable_id_collisions(&type_map);
+    assert_eq!(collisions.len(), 1);
+    assert_eq!(collisions[0].0.as_str(), "integration_tests::sid::Local");
+}
@@ -0,0 +1,90 @@
+use std::net::{IpAddr, Ipv4Addr};
+
+use specta::{DataType, DefOpts, PrimitiveType, StringFormat, StructFields, Type};
+
+use crate::ts::assert_ts;
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct Formats {
+    #[specta(format = "uuid")]
+    a: String,
+    #[specta(format = "date-time")]
+    b: Option<String>,
+    #[specta(format = "uri")]
+    c: Vec<String>,
+    #[specta(format = "hex-color")]
+    d: String,
+    e: Ipv4Addr,
+    // Formats can't be applied to non-strings so this is ignored
+    #[specta(format = "uuid")]
+    f: i32,
+}
+
+fn inline<T: Type>() -> DataType {
+    T::inline(
+        DefOpts {
+            parent_inline: false,
+            type_map: &mut Default::default(),
+        },
+        &[],
+    )
+}
+
+fn formatted(format: StringFormat) -> DataType {
+    DataType::Primitive(PrimitiveType::FormattedString(format))
+}
+
+#[test]
+fn string_format() {
+    assert_eq!(inline::<IpAddr>(), formatted(StringFormat::Ip));
+    assert_eq!(inline::<Ipv4Addr>(), formatted(StringFormat::Ipv4));
+    #[cfg(feature = "uuid")]
+    assert_eq!(inline::<uuid::Uuid>(), formatted(StringFormat::Uuid));
+    #[cfg(feature = "chrono")]
+    assert_eq!(
+        inline::<chrono::DateTime<chrono::Utc>>(),
+        formatted(StringFormat::DateTime)
+    );
+    #[cfg(feature = "url")]
+    assert_eq!(inline::<url::Url>(), formatted(StringFormat::Uri));
+
+    let DataType::Struct(s) = inline::<Formats>() else {
+        panic!("expected struct");
+    };
+    let StructFields::Named(fields) = s.fields() else {
+        panic!("expected named fields");
+    };
+    let fields = fields
+        .fields()
+        .iter()
+        .map(|(_, field)| field.ty().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(fields[0], formatted(StringFormat::Uuid));
+    assert_eq!(
+        fields[1],
+        DataType::Nullable(Box::new(formatted(StringFormat::DateTime)))
+    );
+    let DataType::List(list) = &fields[2] else {
+        panic!("expected list");
+    };
+    assert_eq!(list.ty(), &formatted(StringFormat::Uri));
+    assert_eq!(
+        fields[3],
+        formatted(StringFormat::Custom("hex-color".into()))
+    );
+    assert_eq!(fields[4], formatted(StringFormat::Ipv4));
+    assert_eq!(fields[5], DataType::Primitive(PrimitiveType::i32));
+
+    assert_eq!(StringFormat::new("date-time"), StringFormat::DateTime);
+    assert_eq!(StringFormat::DateTime.as_str(), "date-time");
+    assert_eq!(
+        StringFormat::Custom("hex-color".into()).as_str(),
+        "hex-color"
+    );
+
+    assert_ts!(
+        Formats,
+        "{ a: string; b: string | null; c: string[]; d: string; e: string; f: number }"
+    );
+}
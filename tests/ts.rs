@@ -10,7 +10,7 @@ use std::{
 use serde::Serialize;
 use specta::{
     ts::{BigIntExportBehavior, ExportConfig, ExportError, ExportPath, NamedLocation},
-    Any, Type,
+    Any, DefOpts, NamedType, Type, TypeMap,
 };
 
 macro_rules! assert_ts {
@@ -54,6 +54,25 @@ macro_rules! assert_ts_export {
 }
 pub(crate) use assert_ts_export;
 
+/// A [`TypeMap`] containing `T` and every type it depends on.
+pub fn type_map<T: NamedType>() -> TypeMap {
+    let mut type_map = TypeMap::default();
+    let ty = T::definition_named_data_type(DefOpts {
+        parent_inline: false,
+        type_map: &mut type_map,
+    });
+    type_map.insert(T::SID, Some(ty));
+    type_map
+}
+
+/// An [`ExportConfig`] which only exports `T` and the types it depends on instead of every type in the test binary.
+#[cfg(feature = "export")]
+pub fn export_conf<T: NamedType>() -> ExportConfig {
+    use specta::export::{ExportFilter, TypeSelector};
+
+    ExportConfig::default().filter(ExportFilter::new().root(TypeSelector::of::<T>()))
+}
+
 // TODO: Unit test other `specta::Type` methods such as `::reference(...)`
 
 #[test]
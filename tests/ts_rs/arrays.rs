@@ -1,5 +1,5 @@
 use crate::ts::assert_ts;
-use specta::Type;
+use specta::{ts::ExportConfig, DataType, DefOpts, Type};
 
 #[test]
 fn free() {
@@ -26,3 +26,33 @@ fn newtype() {
 
     assert_ts!(Newtype, "number[]")
 }
+
+#[test]
+fn tuple() {
+    let DataType::List(list) = <[f32; 3]>::inline(
+        DefOpts {
+            parent_inline: false,
+            type_map: &mut Default::default(),
+        },
+        &[],
+    ) else {
+        panic!("expected list");
+    };
+    assert_eq!(list.length(), Some(3));
+
+    let cfg = ExportConfig::default().tuple_array_max_length(4);
+    assert_eq!(
+        specta::ts::inline::<[f32; 3]>(&cfg),
+        Ok("[number, number, number]".into())
+    );
+    assert_eq!(
+        specta::ts::inline::<[Option<String>; 2]>(&cfg),
+        Ok("[string | null, string | null]".into())
+    );
+    assert_eq!(specta::ts::inline::<[f32; 5]>(&cfg), Ok("number[]".into()));
+    assert_eq!(specta::ts::inline::<Vec<f32>>(&cfg), Ok("number[]".into()));
+    assert_eq!(
+        specta::ts::inline::<[f32; 3]>(&cfg.readonly(true)),
+        Ok("readonly [number, number, number]".into())
+    );
+}
@@ -22,6 +22,20 @@ pub struct InvalidToValidType {
     pub(crate) cause: Option<Box<dyn std::error::Error + Send + Sync>>,
 }
 
+// A custom serializer requires the type to be overridden or the field to be skipped.
+#[derive(Type)]
+#[specta(export = false)]
+pub struct CustomSerializer {
+    #[serde(with = "duration_millis")]
+    #[specta(type = u32)]
+    a: std::time::Duration,
+    #[serde(serialize_with = "serialize_as_string")]
+    #[specta(type = String)]
+    b: i32,
+    #[serde(deserialize_with = "deserialize_from_string", skip)]
+    c: i32,
+}
+
 #[test]
 fn type_override() {
     assert_ts!(
@@ -29,4 +43,5 @@ fn type_override() {
         "{ string_ident: string; u32_ident: number; path: string }"
     );
     assert_ts!(InvalidToValidType, "{ cause: any | null }");
+    assert_ts!(CustomSerializer, "{ a: number; b: string }");
 }
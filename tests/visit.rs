@@ -0,0 +1,96 @@
+use specta::{
+    datatype::visit::{
+        visit_type_map, visit_type_map_mut, visit_with_references, walk_datatype,
+        walk_datatype_mut, walk_field, Visitor, VisitorMut,
+    },
+    DataType, DataTypeReference, Field, NamedType, PrimitiveType, Type,
+};
+
+use crate::ts::type_map;
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct Tree {
+    name: String,
+    children: Vec<Tree>,
+    leaf: Option<Leaf>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+pub enum Leaf {
+    A { size: i32 },
+    B(Box<Tree>),
+}
+
+#[derive(Default)]
+struct Collector {
+    fields: Vec<String>,
+    references: Vec<String>,
+}
+
+impl Visitor for Collector {
+    fn visit_field(&mut self, name: Option<&str>, field: &Field) {
+        self.fields
+            .push(name.map(|n| n.to_string()).unwrap_or_else(|| "_".into()));
+        walk_field(self, field);
+    }
+
+    fn visit_reference(&mut self, reference: &DataTypeReference) {
+        self.references.push(reference.name().to_string());
+    }
+}
+
+#[test]
+fn visit_recursive_type() {
+    let type_map = type_map::<Tree>();
+    let ty = type_map[&Tree::SID].as_ref().unwrap();
+
+    let mut collector = Collector::default();
+    visit_with_references(&mut collector, &ty.inner, &type_map);
+
+    // `Tree` is visited inline then once each for the references to `Tree` and `Leaf`.
+    assert_eq!(
+        collector.fields,
+        vec!["name", "children", "leaf", "name", "children", "leaf", "size", "_"]
+    );
+    assert_eq!(
+        collector.references,
+        vec!["Tree", "Leaf", "Tree", "Leaf", "Tree"]
+    );
+}
+
+struct NumbersToStrings;
+
+impl VisitorMut for NumbersToStrings {
+    fn visit_datatype_mut(&mut self, ty: &mut DataType) {
+        if let DataType::Primitive(PrimitiveType::i32) = ty {
+            *ty = DataType::Primitive(PrimitiveType::String);
+        }
+        walk_datatype_mut(self, ty);
+    }
+}
+
+#[test]
+fn visit_mut_type_map() {
+    let mut type_map = type_map::<Tree>();
+
+    visit_type_map_mut(&mut NumbersToStrings, &mut type_map);
+
+    let mut collector = Primitives::default();
+    visit_type_map(&mut collector, &type_map);
+    assert!(collector.0.contains(&PrimitiveType::String));
+    assert!(!collector.0.contains(&PrimitiveType::i32));
+}
+
+#[derive(Default)]
+struct Primitives(Vec<PrimitiveType>);
+
+impl Visitor for Primitives {
+    fn visit_datatype(&mut self, ty: &DataType) {
+        if let DataType::Primitive(ty) = ty {
+            self.0.push(ty.clone());
+        }
+        walk_datatype(self, ty);
+    }
+}
@@ -0,0 +1,90 @@
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+use specta::{
+    ts::{self, ExportConfig, ExportPath, ExportWarning},
+    Any, NamedType, Type,
+};
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct Suspicious<T> {
+    payload: Any,
+    maybe: Option<Option<String>>,
+    result: Result<String, String>,
+    marker: PhantomData<T>,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+pub struct Fine<T> {
+    value: Option<T>,
+    result: Result<String, i32>,
+}
+
+fn warnings<T: NamedType>() -> Vec<ExportWarning> {
+    let warnings = Arc::new(Mutex::new(Vec::new()));
+    let conf = ExportConfig::default().on_warning({
+        let warnings = warnings.clone();
+        move |warning| warnings.lock().unwrap().push(warning)
+    });
+
+    ts::export::<T>(&conf).unwrap();
+    let warnings = warnings.lock().unwrap().drain(..).collect();
+    warnings
+}
+
+#[test]
+fn export_warnings() {
+    assert_eq!(
+        warnings::<Suspicious<()>>(),
+        vec![
+            ExportWarning::UnusedGeneric(ExportPath::new_unsafe("Suspicious"), "T".into()),
+            ExportWarning::Any(ExportPath::new_unsafe("Suspicious -> Suspicious.payload")),
+            ExportWarning::NestedNullable(ExportPath::new_unsafe("Suspicious -> Suspicious.maybe")),
+            ExportWarning::ResultCollapsed(ExportPath::new_unsafe(
+                "Suspicious -> Suspicious.result"
+            )),
+        ]
+    );
+
+    assert_eq!(warnings::<Fine<()>>(), vec![]);
+
+    // Warnings don't change the output
+    assert_eq!(
+        ts::export::<Suspicious<()>>(&ExportConfig::default()),
+        Ok("export type Suspicious<T> = { payload: any; maybe: string | null; result: string; marker: null }".into())
+    );
+}
+
+#[derive(Type)]
+pub struct WarnedTwice {
+    payload: Any,
+}
+
+#[cfg(feature = "export")]
+#[test]
+fn deduplicated_warnings() {
+    use specta::export;
+
+    let warnings = Arc::new(Mutex::new(Vec::new()));
+    let conf = crate::ts::export_conf::<WarnedTwice>()
+        .serde_mode_suffix(true)
+        .on_warning({
+            let warnings = warnings.clone();
+            move |warning| warnings.lock().unwrap().push(warning)
+        });
+
+    let out = export::ts_to_string(&conf).unwrap();
+    assert!(out.contains("export type WarnedTwiceInput = { payload: any }"));
+    assert!(out.contains("export type WarnedTwiceOutput = { payload: any }"));
+
+    assert_eq!(
+        *warnings.lock().unwrap(),
+        vec![ExportWarning::Any(ExportPath::new_unsafe(
+            "WarnedTwice -> WarnedTwice.payload"
+        ))]
+    );
+}